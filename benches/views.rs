@@ -0,0 +1,128 @@
+//! Benchmarks the cost of producing many views from a single owner.
+//!
+//! Run with `cargo bench`. The `per-view bookkeeping` rows emulate the original implementation,
+//! which retained one strong reference per view in a `Vec` owned by the `DependentRc`/`DependentArc`.
+//! Views of the same type now share one issuer per owner, so the memory retained by the owner no
+//! longer grows with the number of views it has handed out - each row reports the bytes still held
+//! once the views themselves have been dropped.
+//!
+//! Producing a view is no faster than it was: finding the issuer costs a map lookup and a downcast
+//! per view, in place of the push onto the `Vec`.
+
+#[macro_use]
+extern crate dependent_view;
+
+use dependent_view::arc::{DependentArc, SyncView};
+use dependent_view::rc::{DependentRc, View};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::rc::{self, Rc};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{self, Arc};
+use std::time::{Duration, Instant};
+
+/// Allocator keeping count of the bytes currently allocated, to measure what the owners retain
+struct Counting;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+trait Dance {
+    fn dance(&self) -> usize;
+}
+
+struct Dancer {
+    id: usize,
+}
+
+impl Dance for Dancer {
+    fn dance(&self) -> usize {
+        self.id
+    }
+}
+
+const VIEWS: usize = 100_000;
+const ROUNDS: usize = 20;
+
+/// Times `round`, which produces `VIEWS` views and returns the owner, and reports the bytes the owner
+/// retains once the views have been dropped
+fn measure<R, F: FnMut() -> R>(name: &str, mut round: F) {
+    let mut best = Duration::MAX;
+    let mut retained = 0;
+    for _ in 0..ROUNDS {
+        let before = ALLOCATED.load(Ordering::Relaxed);
+        let start = Instant::now();
+        let owner = round();
+        best = best.min(start.elapsed());
+        retained = ALLOCATED.load(Ordering::Relaxed) - before;
+        drop(owner);
+    }
+    println!(
+        "{:<40} {:>10.2?} per {} views ({:.2} ns/view), {} bytes retained",
+        name,
+        best,
+        VIEWS,
+        best.as_nanos() as f64 / VIEWS as f64,
+        retained
+    );
+}
+
+fn main() {
+    let dancer = DependentRc::new(Dancer { id: 7 });
+    let view: View<dyn Dance> = to_view!(dancer);
+    assert_eq!(view.upgrade().map(|d| d.dance()), Some(7));
+
+    measure("rc: to_view!", || {
+        let dancer = DependentRc::new(Dancer { id: 0 });
+        for _ in 0..VIEWS {
+            let view: View<dyn Dance> = to_view!(dancer);
+            black_box(view);
+        }
+        dancer
+    });
+
+    measure("rc: per-view bookkeeping", || {
+        let item = Rc::new(Dancer { id: 0 });
+        let mut dependants: Vec<Rc<Dancer>> = Vec::new();
+        for _ in 0..VIEWS {
+            dependants.push(item.clone());
+            let view: rc::Weak<dyn Dance> = Rc::downgrade(&item) as rc::Weak<dyn Dance>;
+            black_box(view);
+        }
+        (item, black_box(dependants))
+    });
+
+    measure("arc: to_view_sync!", || {
+        let dancer = DependentArc::new(Dancer { id: 0 });
+        for _ in 0..VIEWS {
+            let view: SyncView<dyn Dance> = to_view_sync!(dancer);
+            black_box(view);
+        }
+        dancer
+    });
+
+    measure("arc: per-view bookkeeping", || {
+        let item = Arc::new(Dancer { id: 0 });
+        let mut dependants: Vec<Arc<Dancer>> = Vec::new();
+        for _ in 0..VIEWS {
+            dependants.push(item.clone());
+            let view: sync::Weak<dyn Dance> = Arc::downgrade(&item) as sync::Weak<dyn Dance>;
+            black_box(view);
+        }
+        (item, black_box(dependants))
+    });
+}
@@ -1,7 +1,6 @@
 #[macro_use] extern crate dependent_view;
 
-use dependent_view::rc::DependentRc;
-use std::rc::{Rc, Weak};
+use dependent_view::rc::{DependentRc, View};
 
 
 
@@ -24,12 +23,12 @@ impl Prance for Prancer {fn prance(&self)  {println!("P{:?}", self.id);}}
 
 
 pub fn main() {
-    let mut dancers : Vec<Weak<Dance>> = Vec::new();
-    let mut prancers : Vec<Weak<Prance>> = Vec::new();
+    let mut dancers : Vec<View<dyn Dance>> = Vec::new();
+    let mut prancers : Vec<View<dyn Prance>> = Vec::new();
 
     {
-        let mut dancer = DependentRc::new(Dancer { id: 0 });
-        let mut prancer = DependentRc::new(Prancer { id: 0 });
+        let dancer = DependentRc::new(Dancer { id: 0 });
+        let prancer = DependentRc::new(Prancer { id: 0 });
 
         dancers.push(to_view!(dancer));
         prancers.push(to_view!(dancer));
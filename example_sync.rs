@@ -1,9 +1,8 @@
 #[macro_use]
 extern crate dependent_view;
 
-use dependent_view::arc::DependentArc;
+use dependent_view::arc::{DependentArc, SyncView};
 use std::thread;
-use std::sync::{Weak, Arc};
 use std::time;
 
 
@@ -31,12 +30,12 @@ pub fn main() {
     let mut dancers : Vec<DependentArc<Dancer>> = Vec::new();
     let mut prancers : Vec<DependentArc<Prancer>> = Vec::new();
 
-    let mut dance_refs : Vec<Weak<Dance>> = Vec::new();
-    let mut prance_refs : Vec<Weak<Prance>> = Vec::new();
+    let mut dance_refs : Vec<SyncView<dyn Dance>> = Vec::new();
+    let mut prance_refs : Vec<SyncView<dyn Prance>> = Vec::new();
 
     for i in 0..10 {
-        let mut dancer = DependentArc::new(Dancer { id: i });
-        let mut prancer = DependentArc::new(Prancer { id: i+10 });
+        let dancer = DependentArc::new(Dancer { id: i });
+        let prancer = DependentArc::new(Prancer { id: i+10 });
 
         dance_refs.push(to_view_sync!(dancer));
         prance_refs.push(to_view_sync!(prancer));
@@ -10,7 +10,7 @@
 //! # #[macro_use] extern crate dependent_view;
 //! # use dependent_view::arc::DependentArc;
 //! # use std::thread;
-//! # use std::sync::{Weak, Arc};
+//! # use dependent_view::arc::SyncView;
 //! # use std::time;
 //! # trait Dance : Send + Sync {
 //! #    fn dance(&self);
@@ -28,12 +28,12 @@
 //! let mut dancers : Vec<DependentArc<Dancer>> = Vec::new();
 //! let mut prancers : Vec<DependentArc<Prancer>> = Vec::new();
 //! 
-//! let mut dance_refs : Vec<Weak<Dance>> = Vec::new();
-//! let mut prance_refs : Vec<Weak<Prance>> = Vec::new();
+//! let mut dance_refs : Vec<SyncView<dyn Dance>> = Vec::new();
+//! let mut prance_refs : Vec<SyncView<dyn Prance>> = Vec::new();
 //! 
 //! for i in 0..10 {
-//!     let mut dancer = DependentArc::new(Dancer { id: i });
-//!     let mut prancer = DependentArc::new(Prancer { id: i+10 });
+//!     let dancer = DependentArc::new(Dancer { id: i });
+//!     let prancer = DependentArc::new(Prancer { id: i+10 });
 //! 
 //!     dance_refs.push(to_view_sync!(dancer));
 //!     prance_refs.push(to_view_sync!(prancer));
@@ -86,24 +86,38 @@
 //! ```
 
 
-use super::push_ref;
-
-use std::sync::Arc;
-use std::any::Any;
-use std::mem::transmute;
+use std::sync::{Arc, Condvar, Mutex, OnceLock, PoisonError, RwLock, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
 use std::ops::{Deref, DerefMut};
 use std::convert::*;
+use super::{next_owner_id, InteriorMut, TypeIdMap, ViewAs, ViewError, ViewType};
+use std::any::{self, Any, TypeId};
+use std::cmp;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::collections::HashMap;
 
 
 /// Macro for obtaining thread safe views from DependentArc
 ///
+/// The macro accepts any place expression evaluating to a `DependentArc` (or a reference to one), and
+/// comes in two forms:
+///  - `to_view_sync!(owner)` infers the trait to view from the type the result is used as.
+///  - `to_view_sync!(owner => dyn Trait)` names the trait inline, so no type annotation is needed.
+///
 /// # Error
 /// It is a compile time error to use this macro to produce a view for a trait that the underlying struct does not implement.
 /// 
 /// # Examples
 ///
 /// ```
-/// # use std::sync::Weak;
+/// # use dependent_view::arc::SyncView;
 /// # #[macro_use] extern crate dependent_view;
 /// # use dependent_view::arc::DependentArc;
 /// struct ExampleStruct {
@@ -121,105 +135,2593 @@ use std::convert::*;
 /// #          }
 /// }
 /// # fn main() {
-/// let mut item : DependentArc<ExampleStruct> = DependentArc::new(ExampleStruct::new());
-/// let view : Weak<ExampleTrait> = to_view_sync!(item);
+/// let item : DependentArc<ExampleStruct> = DependentArc::new(ExampleStruct::new());
+/// let view : SyncView<dyn ExampleTrait> = to_view_sync!(item);
+/// # }
+/// ```
+///
+/// Owners can be reached through arbitrary expressions, and the trait named inline:
+///
+/// ```
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::arc::DependentArc;
+/// trait Dance { fn dance(&self); }
+/// struct Dancer;
+/// impl Dance for Dancer { fn dance(&self) {} }
+///
+/// struct Stage { owner: DependentArc<Dancer>, owners: Vec<DependentArc<Dancer>> }
+///
+/// # fn main() {
+/// let stage = Stage { owner: DependentArc::new(Dancer), owners: vec![DependentArc::new(Dancer)] };
+/// let boxed = Box::new(DependentArc::new(Dancer));
+///
+/// let lead = to_view_sync!(stage.owner => dyn Dance);
+/// let chorus = to_view_sync!(stage.owners[0] => dyn Dance);
+/// let solo = to_view_sync!(*boxed => dyn Dance);
+/// # for view in [lead, chorus, solo].iter() { view.upgrade().unwrap().dance(); }
+/// # }
+/// ```
+///
+/// Naming a trait the struct does not implement is rejected:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::arc::DependentArc;
+/// trait Dance { fn dance(&self); }
+/// struct Wallflower;
+///
+/// # fn main() {
+/// let owner = DependentArc::new(Wallflower);
+/// let view = to_view_sync!(owner => dyn Dance); // error: `Wallflower: Dance` is not satisfied
 /// # }
 /// ```
 #[macro_export]
 macro_rules! to_view_sync {
-    ($dep:tt) => {
-        unsafe {($dep.into_view_internal_sync::<_, ::std::sync::Weak<_>,_, _, _>(|item| item.clone() as ::std::sync::Arc<_>, |item| ::std::sync::Arc::downgrade(item), |item| unsafe { ::std::mem::transmute(item) }))};
-    }
+    ($dep:expr => $target:ty) => {
+        $crate::arc::DependentArc::into_view_internal_sync(&$dep, |item| item as ::std::sync::Arc<$target>)
+    };
+    ($dep:expr) => {
+        $crate::arc::DependentArc::into_view_internal_sync(&$dep, |item| item as ::std::sync::Arc<_>)
+    };
+}
+
+
+/// Macro for obtaining thread safe mutable views from a `DependentArc<RwLock<T>>` or `DependentArc<Mutex<T>>`
+///
+/// Where `to_view_sync!` produces views of `dyn Trait`, which only give consumers `&self` access,
+/// `to_view_mut_sync!(owner => dyn Trait)` produces a `SyncView<RwLock<dyn Trait>>` (or
+/// `SyncView<Mutex<dyn Trait>>`), so consumers can lock the struct for writing as well. Like
+/// `to_view_sync!`, the trait can also be inferred with `to_view_mut_sync!(owner)`.
+///
+/// To restrict what a consumer can do with the view, wrap it in a `ReadView` or a `WriteView`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::arc::{DependentArc, ReadView, SyncView, WriteView};
+/// # use std::sync::{Mutex, RwLock};
+/// # use std::thread;
+/// trait Counter { fn count(&self) -> usize; fn increment(&mut self); }
+/// struct Clicks(usize);
+/// impl Counter for Clicks {
+///     fn count(&self) -> usize { self.0 }
+///     fn increment(&mut self) { self.0 += 1; }
+/// }
+///
+/// # fn main() {
+/// let clicks = DependentArc::new(RwLock::new(Clicks(0)));
+/// let view : SyncView<RwLock<dyn Counter>> = to_view_mut_sync!(clicks => dyn Counter);
+/// thread::spawn(move || view.upgrade().unwrap().write().unwrap().increment()).join().unwrap();
+///
+/// let display = ReadView::from(to_view_mut_sync!(clicks => dyn Counter));
+/// assert_eq!(display.read(|counter| counter.count()), Some(1));
+///
+/// let taps = DependentArc::new(Mutex::new(Clicks(0)));
+/// let input = WriteView::from(to_view_mut_sync!(taps => dyn Counter));
+/// input.write(|counter| counter.increment());
+/// assert_eq!(input.read_only().read(|counter| counter.count()), Some(1));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! to_view_mut_sync {
+    ($dep:expr => $target:ty) => {
+        $crate::arc::DependentArc::into_mut_view_internal_sync::<$target>(&$dep, |item| item as ::std::sync::Arc<_>)
+    };
+    ($dep:expr) => {
+        $crate::arc::DependentArc::into_mut_view_internal_sync(&$dep, |item| item as ::std::sync::Arc<_>)
+    };
 }
 
 
-/// `DependentArc<T>` is a simple wrapper around the `Arc<T>`  type, imbuing it with the capability to provide thread safe "views" (`Weak<Trait>`) of non-owned structs to separate components of a system. 
+/// `DependentArc<T>` is a simple wrapper around the `Arc<T>`  type, imbuing it with the capability to provide thread safe "views" (`SyncView<Trait>`) of non-owned structs to separate components of a system. 
+///
+/// The `Arc<T>` is retained in state shared with the views, which upgrade by cloning it - thus when
+/// the `DependentArc` is dropped, all of the views are automatically invalidated. The struct can be
+/// replaced through `&self` from any thread, after which views upgrade to the new one.
+///
+/// Views are issued per type of view handed out: the `DependentArc` keeps one entry per type, keyed
+/// by its `TypeId`, so repeatedly requesting a view of the same trait reuses that entry rather than
+/// growing the owner. Revoking the views of a trait retires its entry - views handed out afterwards
+/// are issued through a fresh one.
+///
+/// Views are produced through `&self`, so an owner shared between threads can hand out views from
+/// all of them at once:
+///
+/// ```
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::arc::DependentArc;
+/// # use std::thread;
+/// trait Dance { fn dance(&self); }
+/// struct Dancer;
+/// impl Dance for Dancer { fn dance(&self) {} }
+///
+/// # fn main() {
+/// let dancer = DependentArc::new(Dancer);
+/// let views : Vec<_> = thread::scope(|s| {
+///     let workers : Vec<_> = (0..4).map(|_| s.spawn(|| to_view_sync!(dancer => dyn Dance))).collect();
+///     workers.into_iter().map(|worker| worker.join().unwrap()).collect()
+/// });
 ///
-/// Internally, it does this by retaining an `Arc<Trait>` for each view you make - thus when the
-/// `DependentArc` is dropped, all of the weak references are automatically invalidated.
-pub struct DependentArc<T> {
-    item: Arc<T>,
-    dependants: Vec<Arc<Any + Send + Sync>>
+/// dancer.revoke::<dyn Dance>();
+/// assert!(views.iter().all(|view| view.upgrade().is_none()));
+/// # }
+/// ```
+pub struct DependentArc<T: ?Sized> {
+    shared: Arc<Shared<T>>
+}
+
+
+/// A type of view handed out by a `DependentArc`, along with the issuer its views upgrade through
+struct Entry {
+    view_type: ViewType,
+    issuer: Arc<dyn Issue>
 }
 
+/// An issuer of views handed out with a handle or into a group, or of projections, tracked so it can
+/// be revoked with the rest of its kind
+struct Issued {
+    view_type: ViewType,
+    /// whether the issuer issues projections, which are only revoked through their handles
+    projection: bool,
+    issuer: Weak<dyn Issue>
+}
 
 
-impl<T> DependentArc<T> {
-    pub fn new(item: T) -> DependentArc<T> {
-        DependentArc {
-            item: Arc::new(item),
-            dependants: Vec::new()
+/// State of a `DependentArc` shared with the issuers of its views, along with the issuers it has handed out
+struct Shared<T: ?Sized> {
+    id: u64,
+    /// views hold the read lock while upgrading, so holding the write lock excludes any upgrades
+    slot: RwLock<Slot<T>>,
+    views: RwLock<TypeIdMap<Entry>>,
+    issued: Mutex<Vec<Issued>>,
+    tracker: Arc<Tracker>,
+    /// why the views can no longer be upgraded, once the owner has gone - shared with `Views` handles,
+    /// which can outlive the rest of the state. Only set under the write lock of the slot.
+    ended: Arc<OnceLock<ViewError>>
+}
+
+/// The reference views are upgraded through, along with whether upgrading is currently allowed
+struct Slot<T: ?Sized> {
+    /// taken out while the owned struct is borrowed mutably or waited on to be released
+    item: Option<Arc<T>>,
+    borrowed: bool,
+    suspended: usize
+}
+
+impl<T: ?Sized> Shared<T> {
+    /// Constructs the state of the owner numbered `id`, whose views upgrade to `item`
+    fn new(id: u64, item: Option<Arc<T>>) -> Shared<T> {
+        Shared {
+            id,
+            slot: RwLock::new(Slot { item, borrowed: false, suspended: 0 }),
+            views: RwLock::new(TypeIdMap::default()),
+            issued: Mutex::new(Vec::new()),
+            tracker: Arc::default(),
+            ended: Arc::default()
         }
     }
 
-    /// internal hidden function used to produce a Weak reference
-    /// # Warn
-    /// This function should only be called through the `to_view_sync!` macro. It is not intended for direct use.
-    /// # Remarks
-    /// This struct works by cloning the underlying Arc, using the fact that when cloning, it is possible
-    /// to upcast an `Arc<Concrete>` to an `Arc<Trait>`.
-    /// From this point, the `Arc<Trait>` is transmuted into an `Arc<Any>`. While this is an unsafe,
-    /// unchecked cast, we just want to keep the on Drop functionality of `Arc<Any>` and don't provide
-    /// any ways to use the trait, so it shouldn't be a problem
-    #[doc(hidden)]
-    pub unsafe fn into_view_internal_sync<X,Y,G,F, H>(&mut self, conversion: F, downgrade: G, to_any: H) -> Y
-    where F : FnOnce(&Arc<T>) -> X,
-    G : FnOnce(&X) -> Y,
-    H : FnOnce(X) -> Arc<Any + Send + Sync>
-    {
-        let reference : X = conversion(&self.item);
-        let reference : Arc<Any + Send + Sync> = to_any(reference);
-        let reference : &Arc<Any + Send + Sync> = push_ref(&mut self.dependants, reference);
-        let reference : &X =  transmute(reference) ;
-        downgrade(reference)
+    /// Invalidates every view handed out by the owner so far for `reason`
+    fn invalidate_all(&self, reason: ViewError) {
+        // collected first, so the callbacks of the views can use the owner's state
+        let mut issuers : Vec<Arc<dyn Issue>> = self.views.write().unwrap_or_else(PoisonError::into_inner).drain()
+            .map(|(_, entry)| entry.issuer)
+            .collect();
+        issuers.extend(self.issued.lock().unwrap_or_else(PoisonError::into_inner).drain(..).filter_map(|issued| issued.issuer.upgrade()));
+        invalidate(issuers, reason);
+    }
+
+    /// Detaches the views for good as the owner goes away, then invalidates them for `reason`
+    fn end(&self, reason: ViewError) {
+        let slot = self.slot.write().unwrap_or_else(PoisonError::into_inner);
+        if self.ended.set(reason).is_err() {
+            return;
+        }
+        drop(slot);
+        self.invalidate_all(reason);
+    }
+
+    /// Constructs the invalidation state of a new issuer, invalidated already if the owner has ended
+    fn invalidated(&self) -> OnceLock<ViewError> {
+        (*self.ended).clone()
+    }
+
+    /// Constructs a handle producing views through `shared`
+    fn views(shared: &Arc<Shared<T>>) -> Views<T> {
+        Views { id: shared.id, shared: Arc::downgrade(shared), ended: shared.ended.clone() }
     }
 
+    /// Upgrades the owned struct, unless the owner has been suspended or the struct is unavailable
+    fn try_upgrade(&self) -> Result<Arc<T>, ViewError> {
+        let slot = self.slot.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(reason) = self.ended.get() {
+            return Err(*reason);
+        }
+        if slot.suspended > 0 {
+            return Err(ViewError::Suspended);
+        }
+        if slot.borrowed {
+            return Err(ViewError::Borrowed);
+        }
+        // the struct is only taken out for good along with the owner, once it has been set
+        slot.item.clone().ok_or(ViewError::Uninitialized)
+    }
+
+    /// Returns the owned struct, which owners hold outside of `get_mut` and `drop_and_wait`
+    fn item(&self) -> Arc<T> {
+        self.slot.read().unwrap_or_else(PoisonError::into_inner).item.clone()
+            .expect("owners hold their struct while they can be borrowed")
+    }
 }
 
-impl<T> Deref for DependentArc<T> {
-    type Target = Arc<T>;
+/// Invalidates the views of a dropped owner, or expires them if it was lazy and never set. Owners
+/// invalidate their views when dropped, so this only catches views issued while they were.
+impl<T: ?Sized> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let slot = self.slot.get_mut().unwrap_or_else(PoisonError::into_inner);
+        let reason = *self.ended.get_or_init(|| match slot.item {
+            Some(_) => ViewError::Dropped,
+            None => ViewError::Expired
+        });
+        self.invalidate_all(reason);
+    }
+}
 
-    fn deref(&self) -> &Arc<T> {
-        &self.item
+/// Invalidates `issuers` for `reason`, only running their callbacks once every one of them has been,
+/// so that a callback can't upgrade a view about to be invalidated
+fn invalidate(issuers: Vec<Arc<dyn Issue>>, reason: ViewError) {
+    let callbacks : Vec<Callback> = issuers.iter().flat_map(|issuer| issuer.invalidate(reason)).collect();
+    for callback in callbacks {
+        callback(reason);
     }
 }
-impl<T> DerefMut for DependentArc<T> {
 
-    fn deref_mut(&mut self) -> &mut Arc<T> {
-        &mut self.item
+/// Lets owners waiting for the references to their struct to be released hear of those released
+/// through their views. References borrowed through `SyncView::with` and `StrictView`s are counted
+/// while held, so waiters know whether any reference left is one which can't notify them.
+#[derive(Default)]
+struct Tracker {
+    /// how many threads or tasks are waiting
+    waiters: AtomicUsize,
+    /// references borrowed through views, which notify the tracker once released
+    borrows: AtomicUsize,
+    /// the task polling `DropAsync`, if any
+    waker: Mutex<Option<Waker>>,
+    released: Condvar
+}
+
+impl Tracker {
+    /// Counts a reference borrowed through a view, to be released through `release`
+    fn borrow(&self) {
+        self.borrows.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Drops a reference counted by `borrow`, waking the owners waiting for it
+    fn release<R>(&self, reference: R) {
+        // uncounted before being dropped, so the count never exceeds the references it counts
+        self.borrows.fetch_sub(1, Ordering::SeqCst);
+        drop(reference);
+        // references are released far more often than they are waited for. A waiter registers
+        // before checking for references, so either it sees this one released or this sees it.
+        if self.waiters.load(Ordering::SeqCst) == 0 {
+            return;
+        }
+        // taking the lock waits for a waiter checking for references to start waiting
+        let waker = self.waker.lock().unwrap_or_else(PoisonError::into_inner).take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        self.released.notify_all();
+    }
+
+    /// Registers a thread or task waiting for references to be released
+    fn enter(&self) {
+        self.waiters.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Unregisters a waiter registered with `enter`
+    fn leave(&self) {
+        self.waiters.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Counts the references to `item` beyond the `held` ones its owner holds which can't notify the
+    /// tracker when released, returning `None` once there are no references beyond those at all.
+    /// Weak references count if `exclusive`, as they keep `Arc::get_mut` from succeeding.
+    fn outstanding<T: ?Sized>(&self, item: &Arc<T>, held: usize, exclusive: bool) -> Option<usize> {
+        // loaded first: borrowed references released since are only uncounted afterwards
+        let borrows = self.borrows.load(Ordering::SeqCst);
+        let strong = Arc::strong_count(item) - held;
+        let weak = if exclusive { Arc::weak_count(item) } else { 0 };
+        if strong == 0 && weak == 0 {
+            return None;
+        }
+        Some(strong.saturating_sub(borrows) + weak)
+    }
+
+    /// Blocks until `outstanding` returns `None`, returning `true`, or `deadline` has passed, returning
+    /// `false`. `outstanding` is checked whenever a borrowed reference is released, and while it counts
+    /// references which can't notify the tracker, with an increasing interval of up to
+    /// `RECHECK_INTERVAL`.
+    fn wait<F: FnMut() -> Option<usize>>(&self, mut outstanding: F, deadline: Option<Instant>) -> bool {
+        self.enter();
+        let mut lock = self.waker.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut interval = Duration::from_micros(10);
+        let outcome = loop {
+            let Some(unnotified) = outstanding() else {
+                break true;
+            };
+            let left = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+            if left.is_some_and(|left| left.is_zero()) {
+                break false;
+            }
+            let recheck = (unnotified > 0).then_some(interval);
+            lock = match recheck.into_iter().chain(left).min() {
+                Some(timeout) => self.released.wait_timeout(lock, timeout).unwrap_or_else(PoisonError::into_inner).0,
+                None => self.released.wait(lock).unwrap_or_else(PoisonError::into_inner)
+            };
+            if recheck.is_some() {
+                interval = (interval * 2).min(RECHECK_INTERVAL);
+            }
+        };
+        drop(lock);
+        self.leave();
+        outcome
+    }
+
+    /// Polls for `outstanding` to return `None`, as `wait`, for a task registered with `enter`. The
+    /// task is woken when a borrowed reference is released, and while `outstanding` counts references
+    /// which can't notify the tracker, every `RECHECK_INTERVAL`.
+    fn poll<F: FnOnce() -> Option<usize>>(&self, cx: &mut Context<'_>, outstanding: F) -> Poll<()> {
+        let mut waker = self.waker.lock().unwrap_or_else(PoisonError::into_inner);
+        match outstanding() {
+            None => {
+                *waker = None;
+                Poll::Ready(())
+            }
+            Some(unnotified) => {
+                *waker = Some(cx.waker().clone());
+                drop(waker);
+                if unnotified > 0 {
+                    recheck(cx.waker());
+                }
+                Poll::Pending
+            }
+        }
     }
 }
 
-/// Returns a reference to the underlying `Arc` struct
-impl<T> AsRef<Arc<T>> for DependentArc<T> {
-    fn as_ref(&self) -> &Arc<T> {
-        &self.item
+/// Type erased resumption of a suspended `DependentArc`
+trait Suspend: Send + Sync {
+    fn resume(&self);
+}
+
+impl<T: ?Sized + Send + Sync> Suspend for Shared<T> {
+    fn resume(&self) {
+        self.slot.write().unwrap_or_else(PoisonError::into_inner).suspended -= 1;
     }
 }
 
-/// Returns a mutable reference to the underlying `Arc` struct
-impl<T> AsMut<Arc<T>> for DependentArc<T> {
-    fn as_mut(&mut self) -> &mut Arc<T> {
-        &mut self.item
+
+/// Type erased operations on the issuer of a type of view
+trait Issue: Any + Send + Sync {
+    /// Invalidates the views of the issuer for `reason`, unless they have been invalidated already,
+    /// returning the callbacks left to run
+    fn invalidate(&self, reason: ViewError) -> Vec<Callback>;
+    fn invalidated(&self) -> Option<ViewError>;
+}
+
+/// Upgrades views of type `U`, erasing the type of the underlying struct
+trait Source<U: ?Sized>: Cancel {
+    fn try_upgrade(&self) -> Result<Arc<U>, ViewError>;
+    /// Upgrades the view to a reference counted by the owner's tracker, to be released through it
+    fn borrow(&self) -> Option<(Arc<U>, Arc<Tracker>)>;
+    fn subscribe(&self, callback: Callback) -> Option<u64>;
+    fn owner(&self) -> u64;
+    fn is_alive(&self) -> bool;
+}
+
+/// Cancellation of the callbacks registered on an issuer
+trait Cancel: Send + Sync {
+    fn cancel(&self, key: u64) -> bool;
+}
+
+/// A callback registered with `on_invalidate`
+type Callback = Box<dyn FnOnce(ViewError) + Send>;
+
+/// Callbacks registered on the views of an issuer, run once they are invalidated
+#[derive(Default)]
+struct Callbacks {
+    next: u64,
+    pending: Vec<(u64, Callback)>
+}
+
+/// Issues the views of type `U` of a `DependentArc<T>`
+struct Issuer<T: ?Sized, U: ?Sized> {
+    owner: u64,
+    shared: Weak<Shared<T>>,
+    upcast: fn(Arc<T>) -> Arc<U>,
+    invalidated: OnceLock<ViewError>,
+    callbacks: Mutex<Callbacks>
+}
+
+impl<T: ?Sized + Send + Sync + 'static, U: ?Sized + 'static> Issue for Issuer<T, U> {
+    fn invalidate(&self, reason: ViewError) -> Vec<Callback> {
+        if self.invalidated.set(reason).is_err() {
+            return Vec::new();
+        }
+        // callbacks registered from here on run straight away, so the pending ones are final
+        let pending = mem::take(&mut self.callbacks.lock().unwrap_or_else(PoisonError::into_inner).pending);
+        pending.into_iter().map(|(_, callback)| callback).collect()
+    }
+
+    fn invalidated(&self) -> Option<ViewError> {
+        self.invalidated.get().copied()
     }
 }
 
+impl<T: ?Sized + Send + Sync, U: ?Sized> Source<U> for Issuer<T, U> {
+    fn try_upgrade(&self) -> Result<Arc<U>, ViewError> {
+        if let Some(reason) = self.invalidated.get() {
+            return Err(*reason);
+        }
+        self.shared.upgrade().ok_or(ViewError::Dropped)?.try_upgrade().map(self.upcast)
+    }
 
-/// Constructs a DependentArc from a `Arc`, imbuing it with the capability to produce views.
-impl <T> From<Arc<T>> for DependentArc<T> {
-    fn from(item: Arc<T>) -> DependentArc<T> {
-        DependentArc {
-            item,
-            dependants: Vec::new()
+    fn borrow(&self) -> Option<(Arc<U>, Arc<Tracker>)> {
+        if self.invalidated.get().is_some() {
+            return None;
+        }
+        let shared = self.shared.upgrade()?;
+        let item = shared.try_upgrade().ok()?;
+        shared.tracker.borrow();
+        Some(((self.upcast)(item), shared.tracker.clone()))
+    }
+
+    fn subscribe(&self, callback: Callback) -> Option<u64> {
+        let mut callbacks = self.callbacks.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(reason) = self.invalidated.get() {
+            drop(callbacks);
+            callback(*reason);
+            return None;
+        }
+        let key = callbacks.next;
+        callbacks.next += 1;
+        callbacks.pending.push((key, callback));
+        Some(key)
+    }
+
+    fn owner(&self) -> u64 {
+        self.owner
+    }
+
+    fn is_alive(&self) -> bool {
+        self.invalidated.get().is_none() && self.shared.strong_count() > 0
+    }
+}
+
+impl<T: ?Sized + Send + Sync, U: ?Sized> Cancel for Issuer<T, U> {
+    fn cancel(&self, key: u64) -> bool {
+        let mut callbacks = self.callbacks.lock().unwrap_or_else(PoisonError::into_inner);
+        let count = callbacks.pending.len();
+        callbacks.pending.retain(|(pending, _)| *pending != key);
+        callbacks.pending.len() < count
+    }
+}
+
+
+/// Upgrades projections to a field of type `U`, erasing the type of the underlying struct
+trait Project<U: ?Sized>: Send + Sync {
+    fn try_upgrade(&self) -> Result<Arc<dyn Any + Send + Sync>, ViewError>;
+    fn project<'a>(&self, item: &'a (dyn Any + Send + Sync)) -> &'a U;
+}
+
+/// Issues the projections of a `DependentArc<T>` through `F`
+struct Projector<T, F> {
+    shared: Weak<Shared<T>>,
+    project: F,
+    invalidated: OnceLock<ViewError>
+}
+
+impl<T: Send + Sync + 'static, F: Send + Sync + 'static> Issue for Projector<T, F> {
+    fn invalidate(&self, reason: ViewError) -> Vec<Callback> {
+        let _ = self.invalidated.set(reason);
+        Vec::new()
+    }
+
+    fn invalidated(&self) -> Option<ViewError> {
+        self.invalidated.get().copied()
+    }
+}
+
+impl<T: Send + Sync + 'static, U: ?Sized, F: Fn(&T) -> &U + Send + Sync> Project<U> for Projector<T, F> {
+    fn try_upgrade(&self) -> Result<Arc<dyn Any + Send + Sync>, ViewError> {
+        if let Some(reason) = self.invalidated.get() {
+            return Err(*reason);
         }
+        self.shared.upgrade().ok_or(ViewError::Dropped)?.try_upgrade().map(|item| item as Arc<dyn Any + Send + Sync>)
+    }
+
+    fn project<'a>(&self, item: &'a (dyn Any + Send + Sync)) -> &'a U {
+        (self.project)(item.downcast_ref().expect("projections are only handed their own owner's struct"))
     }
 }
 
-/// Unwraps the `DependentArc`, returning it's internal `Arc`
+
+
+/// Issues a view of type `U` through the cached issuer of that type, creating it for the first view
+fn issue<T, U>(shared: &Arc<Shared<T>>, upcast: fn(Arc<T>) -> Arc<U>) -> SyncView<U>
+where T : ?Sized + Send + Sync + 'static, U : ?Sized + 'static
+{
+    let cached = shared.views.read().unwrap_or_else(PoisonError::into_inner).get(&TypeId::of::<U>()).map(|entry| entry.issuer.clone());
+    // another thread may have issued the first view of this type since the lookup, in which case
+    // its issuer is shared rather than replaced, so that `revoke` still reaches every view
+    let issuer = cached.unwrap_or_else(|| shared.views.write().unwrap_or_else(PoisonError::into_inner)
+        .entry(TypeId::of::<U>())
+        .or_insert_with(|| Entry {
+            view_type: ViewType::of::<U>(),
+            issuer: Arc::new(Issuer { owner: shared.id, shared: Arc::downgrade(shared), upcast, invalidated: shared.invalidated(), callbacks: Default::default() })
+        })
+        .issuer.clone());
+    let issuer = (issuer as Arc<dyn Any + Send + Sync>).downcast::<Issuer<T, U>>()
+        .expect("view entries are keyed by the TypeId of their view type");
+    SyncView { source: issuer }
+}
+
+
+/// A view of a struct owned by a `DependentArc`, as produced by `to_view_sync!` or `DependentArc::view`.
+///
+/// Like a `std::sync::Weak<U>`, a view does not keep the struct alive, and must be upgraded to be used. Views
+/// cease to be upgradable once the owning `DependentArc` is dropped, or the view has been revoked, and
+/// can't be upgraded while the `DependentArc` is suspended.
+pub struct SyncView<U: ?Sized> {
+    source: Arc<dyn Source<U>>
+}
+
+impl<U: ?Sized> SyncView<U> {
+    /// Attempts to upgrade the view to an `Arc<U>`, returning `None` if the owning `DependentArc`
+    /// has been dropped or suspended, or the view has been revoked.
+    pub fn upgrade(&self) -> Option<Arc<U>> {
+        self.source.try_upgrade().ok()
+    }
+
+    /// Attempts to upgrade the view to an `Arc<U>`, returning the reason the view is unavailable if it
+    /// can't be upgraded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use dependent_view::{impl_views, ViewError};
+    /// trait Service { fn call(&self); }
+    /// struct Cache;
+    /// impl Service for Cache { fn call(&self) {} }
+    /// impl_views!(Cache: dyn Service + Send + Sync);
+    ///
+    /// let cache = DependentArc::<Cache>::lazy();
+    /// let service = cache.view::<dyn Service + Send + Sync>();
+    /// assert_eq!(service.try_upgrade().err(), Some(ViewError::Uninitialized));
+    ///
+    /// // the cache is never set
+    /// drop(cache);
+    /// let error = service.try_upgrade().err().unwrap();
+    /// assert_eq!(error, ViewError::Expired);
+    /// assert_eq!(error.to_string(), "the owner of the view was dropped before being initialized");
+    /// ```
+    pub fn try_upgrade(&self) -> Result<Arc<U>, ViewError> {
+        self.source.try_upgrade()
+    }
+
+    /// Upgrades the view for the duration of `f`, returning `None` without calling it if the
+    /// view can't be upgraded.
+    ///
+    /// Unlike references returned by `upgrade`, releasing the reference wakes the owner straight away
+    /// if it is waiting for it in `DependentArc::drop_and_wait` or `DependentArc::drop_async`.
+    pub fn with<R, F: FnOnce(&U) -> R>(&self, f: F) -> Option<R> {
+        let (value, tracker) = self.source.borrow()?;
+        // released through the guard even if `f` panics, so the tracker's count stays exact
+        let value = ViewRef { value: Some(value), tracker: &tracker };
+        Some(f(&value))
+    }
+
+    /// Returns whether the view may still be upgraded: `false` once the owning `DependentArc` has been
+    /// dropped or the view has been revoked. A view of a suspended or borrowed owner is still alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::arc::DependentArc;
+    /// trait Handle { fn handle(&self) -> u32; }
+    /// struct Request;
+    /// impl Handle for Request { fn handle(&self) -> u32 { 200 } }
+    /// impl_views!(Request: dyn Handle + Send + Sync);
+    ///
+    /// # fn main() {
+    /// let request = DependentArc::new(Request);
+    /// let (view, handle) = request.revocable_view::<dyn Handle + Send + Sync>();
+    /// let other = request.view::<dyn Handle + Send + Sync>();
+    /// assert_eq!(view.with(|request| request.handle()), Some(200));
+    ///
+    /// let suspended = request.suspend();
+    /// assert!(view.upgrade().is_none());
+    /// assert!(view.is_alive());
+    /// drop(suspended);
+    ///
+    /// handle.revoke();
+    /// assert!(!view.is_alive());
+    /// assert!(other.is_alive());
+    ///
+    /// drop(request);
+    /// assert!(!other.is_alive());
+    /// assert_eq!(other.with(|request| request.handle()), None);
+    /// # }
+    /// ```
+    pub fn is_alive(&self) -> bool {
+        self.source.is_alive()
+    }
+
+    /// Registers `callback` to run once the view is invalidated - when the owning `DependentArc` is dropped
+    /// or the view is revoked - with the reason it was. If the view already has been, `callback` runs
+    /// straight away.
+    ///
+    /// The callback runs exactly once, unless it is cancelled through the returned handle first.
+    /// Dropping the handle leaves it registered, while dropping every clone of the view may drop it
+    /// without running.
+    ///
+    /// The callback runs on the thread that invalidates the view: the one dropping the `DependentArc` (or
+    /// `LazyArc`) or revoking the view - or the calling thread, if it runs straight away. No lock of the
+    /// owner is held while it runs, so it may upgrade and produce views of any owner, including this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use dependent_view::{impl_views, ViewError};
+    /// # use std::sync::mpsc;
+    /// # use std::thread;
+    /// trait Job { fn run(&self); }
+    /// struct Upload;
+    /// impl Job for Upload { fn run(&self) {} }
+    /// impl_views!(Upload: dyn Job + Send + Sync);
+    ///
+    /// let upload = DependentArc::new(Upload);
+    /// let job = upload.view::<dyn Job + Send + Sync>();
+    /// let (cancelled, on_cancel) = mpsc::channel();
+    /// job.on_invalidate(move |reason| cancelled.send(reason).unwrap());
+    ///
+    /// let retried = upload.view::<dyn Job + Send + Sync>();
+    /// let handle = retried.on_invalidate(|_| panic!("cancelled callbacks never run"));
+    /// assert!(handle.cancel());
+    ///
+    /// // the callback runs on the thread dropping the owner
+    /// thread::spawn(move || drop(upload)).join().unwrap();
+    /// assert_eq!(on_cancel.recv().unwrap(), ViewError::Dropped);
+    ///
+    /// // registering on an invalidated view runs the callback straight away
+    /// let (sender, receiver) = mpsc::channel();
+    /// job.on_invalidate(move |reason| sender.send(reason).unwrap());
+    /// assert_eq!(receiver.try_recv().unwrap(), ViewError::Dropped);
+    /// ```
+    pub fn on_invalidate<F: FnOnce(ViewError) + Send + 'static>(&self, callback: F) -> CallbackHandle {
+        let key = self.source.subscribe(Box::new(callback));
+        CallbackHandle { source: Arc::downgrade(&self.source) as Weak<dyn Cancel>, key }
+    }
+
+    /// Returns a future resolving once the view is invalidated - when the owning `DependentArc` is
+    /// dropped or the view is revoked - to the reason it was. It only depends on `std`, so it works with
+    /// any executor, e.g. as a cancellation token for a task tied to the owner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use dependent_view::{impl_views, ViewError};
+    /// # use std::future::Future;
+    /// # use std::pin::pin;
+    /// # use std::sync::Arc;
+    /// # use std::task::{Context, Poll, Wake, Waker};
+    /// # use std::thread::{self, Thread};
+    /// # struct Unpark(Thread);
+    /// # impl Wake for Unpark { fn wake(self: Arc<Self>) { self.0.unpark(); } }
+    /// # fn block_on<F: Future>(future: F) -> F::Output {
+    /// #     let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    /// #     let mut future = pin!(future);
+    /// #     loop {
+    /// #         if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&waker)) { return output; }
+    /// #         thread::park();
+    /// #     }
+    /// # }
+    /// trait Job { fn step(&self); }
+    /// struct Crawl;
+    /// impl Job for Crawl { fn step(&self) {} }
+    /// impl_views!(Crawl: dyn Job + Send + Sync);
+    ///
+    /// let crawl = DependentArc::new(Crawl);
+    /// let job = crawl.view::<dyn Job + Send + Sync>();
+    /// let closed = job.closed();
+    ///
+    /// thread::spawn(move || drop(crawl));
+    /// assert_eq!(block_on(closed), ViewError::Dropped);
+    /// ```
+    pub fn closed(&self) -> Closed {
+        let readiness = Arc::new(Readiness::default());
+        let settle = readiness.clone();
+        let key = self.source.subscribe(Box::new(move |reason| settle.settle(reason)));
+        Closed { readiness, source: self.source.clone() as Arc<dyn Cancel>, key }
+    }
+
+    /// Blocks until the view is invalidated, returning the reason it was, or `None` if it still
+    /// hasn't been once `timeout` has passed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use dependent_view::{impl_views, ViewError};
+    /// # use std::thread;
+    /// # use std::time::Duration;
+    /// # trait Job { fn step(&self); }
+    /// # struct Crawl;
+    /// # impl Job for Crawl { fn step(&self) {} }
+    /// # impl_views!(Crawl: dyn Job + Send + Sync);
+    /// let crawl = DependentArc::new(Crawl);
+    /// let (job, handle) = crawl.revocable_view::<dyn Job + Send + Sync>();
+    ///
+    /// let worker = thread::spawn(move || {
+    ///     while job.wait_closed(Duration::from_millis(1)).is_none() {
+    ///         job.with(|job| job.step());
+    ///     }
+    ///     job.try_upgrade().err()
+    /// });
+    ///
+    /// handle.revoke();
+    /// assert_eq!(worker.join().unwrap(), Some(ViewError::Revoked));
+    /// ```
+    pub fn wait_closed(&self, timeout: Duration) -> Option<ViewError> {
+        self.closed().readiness.wait_timeout(timeout)
+    }
+
+    /// Returns the id of the owning `DependentArc`, as returned by `DependentArc::id`.
+    pub fn owner_id(&self) -> u64 {
+        self.source.owner()
+    }
+
+    /// Returns whether both views were handed out by the same `DependentArc`, whatever their types.
+    pub fn same_owner<V: ?Sized>(&self, other: &SyncView<V>) -> bool {
+        self.owner_id() == other.owner_id()
+    }
+
+    /// Returns the address of the source the view upgrades through, which identifies it
+    fn address(&self) -> usize {
+        Arc::as_ptr(&self.source).cast::<()>().addr()
+    }
+
+    /// Converts the view to a `std::sync::Weak`, or `None` if it can't currently be upgraded.
+    ///
+    /// The returned `Weak` only dies with the struct itself: it is not affected by revoking or
+    /// suspending the view, and keeps upgrading while references upgraded from the view are held.
+    ///
+    /// # Warning
+    ///
+    /// The `Weak` is not tracked by the `DependentArc`, which has to assume it may be upgraded at any
+    /// time: for as long as it is held, `DependentArc::get_mut` returns `None` and
+    /// `DependentArc::drop_and_wait` times out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use dependent_view::impl_views;
+    /// # use std::time::Duration;
+    /// # trait Count { fn count(&self) -> usize; }
+    /// # struct Counter(usize);
+    /// # impl Count for Counter { fn count(&self) -> usize { self.0 } }
+    /// # impl_views!(Counter: dyn Count);
+    /// let mut counter = DependentArc::new(Counter(0));
+    /// let weak = counter.view::<dyn Count>().to_weak().unwrap();
+    ///
+    /// // the owner can't tell the `Weak` won't be upgraded while it is mutating or dropping the struct
+    /// assert!(counter.get_mut().is_none());
+    /// let counter = counter.drop_and_wait(Duration::from_millis(10)).unwrap_err();
+    ///
+    /// drop(weak);
+    /// assert!(counter.drop_and_wait(Duration::from_secs(60)).is_ok());
+    /// ```
+    pub fn to_weak(&self) -> Option<Weak<U>> {
+        self.upgrade().map(|item| Arc::downgrade(&item))
+    }
+}
+
+impl<U: ?Sized> Clone for SyncView<U> {
+    fn clone(&self) -> SyncView<U> {
+        SyncView { source: self.source.clone() }
+    }
+}
+
+/// Views are equal if they upgrade through the same source: views of a type handed out by a `DependentArc`
+/// are equal, while each revocable view, or view issued into a group, is only equal to its clones.
+/// `same_owner` compares the owners of views instead.
+impl<U: ?Sized> PartialEq for SyncView<U> {
+    fn eq(&self, other: &SyncView<U>) -> bool {
+        Arc::ptr_eq(&self.source, &other.source)
+    }
+}
+
+impl<U: ?Sized> Eq for SyncView<U> {}
+
+impl<U: ?Sized> Hash for SyncView<U> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address().hash(state);
+    }
+}
+
+/// Views are ordered by the address of their source, which is arbitrary but consistent with `Eq`.
+impl<U: ?Sized> PartialOrd for SyncView<U> {
+    fn partial_cmp(&self, other: &SyncView<U>) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<U: ?Sized> Ord for SyncView<U> {
+    fn cmp(&self, other: &SyncView<U>) -> cmp::Ordering {
+        self.address().cmp(&other.address())
+    }
+}
+
+impl<U: ?Sized> fmt::Debug for SyncView<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SyncView")
+            .field("owner", &self.owner_id())
+            .field("type", &any::type_name::<U>())
+            .field("alive", &self.is_alive())
+            .finish()
+    }
+}
+
+
+/// A view of a struct owned by a `DependentArc` that borrows data, as produced by `DependentArc::bounded_view`.
+///
+/// Bounded views behave like `SyncView`s, but carry the lifetime `'a` of the data the struct borrows,
+/// so they can't outlive it.
+///
+/// # Limitations
+///
+/// As their type needn't be `'static`, bounded views aren't tracked by their owner the way other views
+/// are. They stop upgrading once the owner is dropped, and can't be upgraded while it is borrowed, but
+/// `revoke`, `revoke_all` and `ViewGroup`s don't affect them - so `try_upgrade` never reports
+/// `ViewError::Revoked` - and they have no `on_invalidate`, `closed` or identity of their own. Owners
+/// of structs that aren't `'static` can't be suspended or projected either, as `suspend` and `project`
+/// require a `'static` struct.
+///
+/// ```
+/// # use dependent_view::{impl_views, ViewError};
+/// # use dependent_view::arc::DependentArc;
+/// trait Parse { fn remaining(&self) -> &str; }
+/// struct Parser<'a> { text: &'a str }
+/// impl<'a> Parse for Parser<'a> { fn remaining(&self) -> &str { self.text } }
+/// impl_views!(<'a> Parser<'a>: dyn Parse + 'a);
+///
+/// let text = String::from("let x = 1;");
+/// let parser = DependentArc::new(Parser { text: &text });
+/// let view = parser.bounded_view::<dyn Parse>();
+///
+/// parser.revoke_all();
+/// assert!(view.upgrade().is_some());
+/// drop(parser);
+/// assert_eq!(view.try_upgrade().err(), Some(ViewError::Dropped));
+/// ```
+pub struct BoundedSyncView<'a, U: ?Sized> {
+    source: Arc<dyn Source<U> + 'a>
+}
+
+impl<U: ?Sized> BoundedSyncView<'_, U> {
+    /// Attempts to upgrade the view to an `Arc<U>`, returning `None` if the owning `DependentArc`
+    /// has been dropped or suspended.
+    pub fn upgrade(&self) -> Option<Arc<U>> {
+        self.source.try_upgrade().ok()
+    }
+
+    /// Attempts to upgrade the view to an `Arc<U>`, returning the reason the view is unavailable if it
+    /// can't be upgraded.
+    pub fn try_upgrade(&self) -> Result<Arc<U>, ViewError> {
+        self.source.try_upgrade()
+    }
+}
+
+impl<'a, U: ?Sized> Clone for BoundedSyncView<'a, U> {
+    fn clone(&self) -> BoundedSyncView<'a, U> {
+        BoundedSyncView { source: self.source.clone() }
+    }
+}
+
+
+/// A thread safe view of a field of a struct owned by a `DependentArc`, as produced by `DependentArc::project`.
+///
+/// Projections are upgradable exactly as long as the other views of their owner.
+pub struct SyncProjection<U: ?Sized> {
+    projector: Arc<dyn Project<U>>
+}
+
+impl<U: ?Sized> SyncProjection<U> {
+    /// Attempts to upgrade the projection, returning `None` if the owning `DependentArc` has been
+    /// dropped or suspended, or the projection has been revoked.
+    pub fn upgrade(&self) -> Option<SyncProjected<U>> {
+        self.try_upgrade().ok()
+    }
+
+    /// Attempts to upgrade the projection, returning the reason it is unavailable if it can't be upgraded.
+    pub fn try_upgrade(&self) -> Result<SyncProjected<U>, ViewError> {
+        let item = self.projector.try_upgrade()?;
+        Ok(SyncProjected { item, projector: self.projector.clone() })
+    }
+}
+
+impl<U: ?Sized> Clone for SyncProjection<U> {
+    fn clone(&self) -> SyncProjection<U> {
+        SyncProjection { projector: self.projector.clone() }
+    }
+}
+
+
+/// An upgraded `SyncProjection`, which keeps the owned struct alive and dereferences to the projected field.
+pub struct SyncProjected<U: ?Sized> {
+    item: Arc<dyn Any + Send + Sync>,
+    projector: Arc<dyn Project<U>>
+}
+
+impl<U: ?Sized> Deref for SyncProjected<U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        self.projector.project(&*self.item)
+    }
+}
+
+impl<U: ?Sized> Clone for SyncProjected<U> {
+    fn clone(&self) -> SyncProjected<U> {
+        SyncProjected { item: self.item.clone(), projector: self.projector.clone() }
+    }
+}
+
+
+/// A read only capability for a thread safe mutable view, given to consumers that must not modify
+/// the struct - they can take the read lock of a `RwLock`, but never the write lock.
 ///
-/// Note: This will invalidate all `Weak<Trait>` views you have constructed from this object.
-impl <T> Into<Arc<T>> for DependentArc<T> {
-    fn into(self) -> Arc<T> {
-        self.item
+/// Produced from a `SyncView<C>` of an `InteriorMut` container `C`, such as those produced by
+/// `to_view_mut_sync!`, or from a `WriteView` with `read_only`.
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::arc::{DependentArc, ReadView};
+/// # use std::sync::RwLock;
+/// # trait Counter { fn increment(&mut self); }
+/// # struct Clicks(usize);
+/// # impl Counter for Clicks { fn increment(&mut self) { self.0 += 1; } }
+/// # fn main() {
+/// let clicks = DependentArc::new(RwLock::new(Clicks(0)));
+/// let display = ReadView::from(to_view_mut_sync!(clicks => dyn Counter));
+/// display.write(|counter| counter.increment()); // error: no method named `write`
+/// # }
+/// ```
+pub struct ReadView<C: ?Sized> {
+    view: SyncView<C>
+}
+
+impl<C: ?Sized + InteriorMut> ReadView<C> {
+    /// Calls `f` with a shared reference to the viewed struct, returning `None` if the view can't be
+    /// upgraded. Blocks until the container can be locked for reading.
+    pub fn read<R, F: FnOnce(&C::Inner) -> R>(&self, f: F) -> Option<R> {
+        self.view.upgrade().map(|lock| lock.read(f))
+    }
+}
+
+impl<C: ?Sized> Clone for ReadView<C> {
+    fn clone(&self) -> ReadView<C> {
+        ReadView { view: self.view.clone() }
+    }
+}
+
+impl<C: ?Sized> From<SyncView<C>> for ReadView<C> {
+    fn from(view: SyncView<C>) -> ReadView<C> {
+        ReadView { view }
+    }
+}
+
+
+/// A writable capability for a thread safe mutable view, which can be narrowed to a `ReadView`.
+///
+/// Produced from a `SyncView<C>` of an `InteriorMut` container `C`, such as those produced by `to_view_mut_sync!`.
+pub struct WriteView<C: ?Sized> {
+    view: SyncView<C>
+}
+
+impl<C: ?Sized + InteriorMut> WriteView<C> {
+    /// Calls `f` with a shared reference to the viewed struct, returning `None` if the view can't be
+    /// upgraded. Blocks until the container can be locked for reading.
+    pub fn read<R, F: FnOnce(&C::Inner) -> R>(&self, f: F) -> Option<R> {
+        self.view.upgrade().map(|lock| lock.read(f))
+    }
+
+    /// Calls `f` with a mutable reference to the viewed struct, returning `None` if the view can't be
+    /// upgraded. Blocks until the container can be locked for writing.
+    pub fn write<R, F: FnOnce(&mut C::Inner) -> R>(&self, f: F) -> Option<R> {
+        self.view.upgrade().map(|lock| lock.write(f))
+    }
+}
+
+impl<C: ?Sized> WriteView<C> {
+    /// Produces a read only capability for the same view
+    pub fn read_only(&self) -> ReadView<C> {
+        ReadView { view: self.view.clone() }
+    }
+}
+
+impl<C: ?Sized> Clone for WriteView<C> {
+    fn clone(&self) -> WriteView<C> {
+        WriteView { view: self.view.clone() }
+    }
+}
+
+impl<C: ?Sized> From<SyncView<C>> for WriteView<C> {
+    fn from(view: SyncView<C>) -> WriteView<C> {
+        WriteView { view }
+    }
+}
+
+
+/// A handle a struct owned by a `DependentArc` can keep to produce views of itself, as passed to the
+/// closure of `DependentArc::new_cyclic` or returned by `DependentArc::views`.
+///
+/// The handle doesn't keep the owner alive - views produced after the owner has been dropped can
+/// never be upgraded.
+pub struct Views<T: ?Sized> {
+    id: u64,
+    shared: Weak<Shared<T>>,
+    ended: Arc<OnceLock<ViewError>>
+}
+
+impl<T: ?Sized + Send + Sync + 'static> Views<T> {
+    /// Produces a view of the struct as a `SyncView<U>`, for any `U` registered with `impl_views!`. Views
+    /// produced while the struct is being constructed can be upgraded once it has been.
+    pub fn view<U: ?Sized + 'static>(&self) -> SyncView<U>
+    where T : ViewAs<U>
+    {
+        match self.shared.upgrade() {
+            Some(shared) => issue(&shared, T::upcast_arc),
+            None => {
+                let issuer = Arc::new(Issuer { owner: self.id, shared: self.shared.clone(), upcast: T::upcast_arc, invalidated: OnceLock::new(), callbacks: Default::default() });
+                // the state records why the owner ended as it goes, so only a struct whose construction
+                // panicked leaves it unset
+                let _ = issuer.invalidated.set(self.ended.get().copied().unwrap_or(ViewError::Expired));
+                SyncView { source: issuer }
+            }
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for Views<T> {
+    fn clone(&self) -> Views<T> {
+        Views { id: self.id, shared: self.shared.clone(), ended: self.ended.clone() }
+    }
+}
+
+
+/// A token that revokes one specific view (and its clones), as produced by `DependentArc::revocable_view`
+/// and `DependentArc::revocable_project`.
+///
+/// Once revoked, the view can no longer be upgraded, while other views of the same `DependentArc`
+/// are unaffected.
+#[derive(Clone)]
+pub struct ViewHandle {
+    issuer: Weak<dyn Issue>
+}
+
+impl ViewHandle {
+    /// Revokes the view this handle was issued with
+    pub fn revoke(&self) {
+        if let Some(issuer) = self.issuer.upgrade() {
+            invalidate(vec![issuer], ViewError::Revoked);
+        }
+    }
+
+    /// Returns whether the view has been revoked. Views that have been dropped count as revoked.
+    pub fn is_revoked(&self) -> bool {
+        self.issuer.upgrade().is_none_or(|issuer| issuer.invalidated().is_some())
+    }
+}
+
+
+/// A handle to a callback registered with `SyncView::on_invalidate`, through which it can be cancelled.
+pub struct CallbackHandle {
+    source: Weak<dyn Cancel>,
+    /// `None` if the callback ran on registration
+    key: Option<u64>
+}
+
+impl CallbackHandle {
+    /// Cancels the callback, returning whether it was still pending - `false` if it has already run.
+    pub fn cancel(&self) -> bool {
+        match (self.source.upgrade(), self.key) {
+            (Some(source), Some(key)) => source.cancel(key),
+            _ => false
+        }
+    }
+}
+
+
+/// A group of views, handed out by any number of `DependentArc`s through `DependentArc::view_in`.
+///
+/// Dropping the group, or calling `clear`, revokes every view issued into it, while the owners
+/// live on. Views issued into the group again after a `clear` can be upgraded as normal.
+///
+/// # Examples
+///
+/// ```
+/// # use dependent_view::arc::{DependentArc, ViewGroup};
+/// # use dependent_view::impl_views;
+/// trait Render { fn render(&self); }
+/// struct Sprite;
+/// impl Render for Sprite { fn render(&self) {} }
+/// impl_views!(Sprite: dyn Render);
+///
+/// let sprites = vec![DependentArc::new(Sprite), DependentArc::new(Sprite)];
+///
+/// let renderer = ViewGroup::new();
+/// let views : Vec<_> = sprites.iter().map(|sprite| sprite.view_in::<dyn Render>(&renderer)).collect();
+/// let other = sprites[0].view::<dyn Render>();
+///
+/// // the renderer is reset
+/// renderer.clear();
+/// assert!(views.iter().all(|view| view.upgrade().is_none()));
+/// assert!(other.upgrade().is_some());
+///
+/// let view = sprites[1].view_in::<dyn Render>(&renderer);
+/// assert!(view.upgrade().is_some());
+/// ```
+#[derive(Default)]
+pub struct ViewGroup {
+    issuers: Mutex<HashMap<(u64, TypeId), Weak<dyn Issue>>>
+}
+
+impl ViewGroup {
+    /// Constructs an empty `ViewGroup`
+    pub fn new() -> ViewGroup {
+        ViewGroup::default()
+    }
+
+    /// Revokes every view issued into the group so far
+    pub fn clear(&self) {
+        let issuers : Vec<_> = self.issuers.lock().unwrap_or_else(PoisonError::into_inner).drain()
+            .filter_map(|(_, issuer)| issuer.upgrade())
+            .collect();
+        invalidate(issuers, ViewError::Revoked);
+    }
+}
+
+impl Drop for ViewGroup {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+
+/// Guard returned by `DependentArc::suspend`. The views of the `DependentArc` resume once every
+/// guard has been dropped.
+#[must_use = "the views of the owner resume as soon as the guard is dropped"]
+pub struct SuspendGuard {
+    shared: Weak<dyn Suspend>
+}
+
+impl Drop for SuspendGuard {
+    fn drop(&mut self) {
+        if let Some(shared) = self.shared.upgrade() {
+            shared.resume();
+        }
+    }
+}
+
+
+
+/// Guard returned by `DependentArc::get_mut`, giving mutable access to the owned struct. The views of
+/// the `DependentArc` can't be upgraded while it is held - nor ever again, should it be forgotten, as
+/// the struct is forgotten with it.
+pub struct ItemMut<'a, T: ?Sized> {
+    /// taken out of the slot until the guard is dropped
+    item: Option<Arc<T>>,
+    shared: &'a Shared<T>
+}
+
+impl<T: ?Sized> Deref for ItemMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.item.as_ref().expect("the struct is only put back on drop")
+    }
+}
+
+impl<T: ?Sized> DerefMut for ItemMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        let item = self.item.as_mut().expect("the struct is only put back on drop");
+        Arc::get_mut(item).expect("views are detached while the struct is borrowed mutably")
+    }
+}
+
+impl<T: ?Sized> Drop for ItemMut<'_, T> {
+    fn drop(&mut self) {
+        let mut slot = self.shared.slot.write().unwrap_or_else(PoisonError::into_inner);
+        slot.item = self.item.take();
+        slot.borrowed = false;
+    }
+}
+
+
+impl<T: ?Sized> DependentArc<T> {
+    /// Constructs a `DependentArc` by wrapping an underlying type
+    pub fn new(item: T) -> DependentArc<T>
+    where T : Sized
+    {
+        DependentArc::from(Arc::new(item))
+    }
+
+    /// Constructs a `LazyArc`, which hands out views before the struct they view exists.
+    pub fn lazy() -> LazyArc<T>
+    where T : Sized
+    {
+        LazyArc {
+            shared: Arc::new(Shared::new(next_owner_id(), None)),
+            fulfil: Fulfil(Arc::default())
+        }
+    }
+
+    /// Constructs a `DependentArc` of a struct that holds views of itself, in the manner of `Arc::new_cyclic`.
+    ///
+    /// `build` is passed a `Views` handle, through which it can produce views of the struct before it
+    /// exists - they can be upgraded once `build` has returned. The struct can keep the handle to
+    /// produce more views of itself later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::{DependentArc, SyncView, Views};
+    /// # use dependent_view::impl_views;
+    /// trait Listener { fn notify(&self) -> &str; }
+    /// struct Widget { name: String, views: Views<Widget>, listeners: Vec<SyncView<dyn Listener + Send + Sync>> }
+    /// impl Listener for Widget { fn notify(&self) -> &str { &self.name } }
+    /// impl_views!(Widget: dyn Listener + Send + Sync);
+    ///
+    /// impl Widget {
+    ///     fn listener(&self) -> SyncView<dyn Listener + Send + Sync> {
+    ///         self.views.view::<dyn Listener + Send + Sync>()
+    ///     }
+    /// }
+    ///
+    /// let widget = DependentArc::new_cyclic(|views| Widget {
+    ///     name: String::from("button"),
+    ///     listeners: vec![views.view::<dyn Listener + Send + Sync>()],
+    ///     views: views.clone()
+    /// });
+    ///
+    /// assert_eq!(widget.load().listeners[0].upgrade().unwrap().notify(), "button");
+    /// let later = widget.load().listener();
+    /// drop(widget);
+    /// assert!(later.upgrade().is_none());
+    /// ```
+    pub fn new_cyclic<F: FnOnce(&Views<T>) -> T>(build: F) -> DependentArc<T>
+    where T : Sized
+    {
+        let shared = Arc::new(Shared::new(next_owner_id(), None));
+        let views = Shared::views(&shared);
+        let item = Arc::new(build(&views));
+        shared.slot.write().unwrap_or_else(PoisonError::into_inner).item = Some(item);
+        DependentArc { shared }
+    }
+
+    /// Returns the id of the `DependentArc`, shared by every view it hands out. Ids are unique across
+    /// owners for the lifetime of the process, so they can be used to tell owners apart in logs.
+    pub fn id(&self) -> u64 {
+        self.shared.id
+    }
+
+    /// Returns a `Views` handle, through which views of the struct can be produced without the owner.
+    pub fn views(&self) -> Views<T> {
+        Shared::views(&self.shared)
+    }
+
+    /// Returns the owned struct, as views currently upgrade to it. The struct may be replaced
+    /// afterwards, leaving the returned reference to the old one.
+    pub fn load(&self) -> Arc<T> {
+        self.shared.item()
+    }
+
+    /// Revokes every view handed out so far. The `DependentArc` keeps working, and views handed out
+    /// afterwards can be upgraded as normal.
+    pub fn revoke_all(&self) {
+        self.shared.invalidate_all(ViewError::Revoked);
+    }
+
+    /// Invalidates every view for good, as if the `DependentArc` had been dropped, for scopes ending while
+    /// the owner is still referenced
+    pub(crate) fn end(&self) {
+        self.shared.end(ViewError::Dropped);
+    }
+
+    /// Returns mutable access to the owned struct, or `None` if a consumer currently holds an upgraded
+    /// reference to it. Views handed out so far don't prevent this, but can't be upgraded until the
+    /// returned guard has been dropped.
+    ///
+    /// A `std::sync::Weak` produced by `SyncView::to_weak` counts as an upgraded reference for as long
+    /// as it is held, even if it is never upgraded.
+    ///
+    /// This returns a guard rather than a `&mut T` as the struct is taken out of the `DependentArc` for
+    /// as long as it is borrowed, so that views upgrading on other threads can't reach it, and is only
+    /// put back once the guard is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use dependent_view::impl_views;
+    /// # trait Count { fn count(&self) -> usize; }
+    /// # struct Counter(usize);
+    /// # impl Count for Counter { fn count(&self) -> usize { self.0 } }
+    /// # impl_views!(Counter: dyn Count);
+    /// let mut counter = DependentArc::new(Counter(0));
+    /// let view = counter.view::<dyn Count>();
+    ///
+    /// counter.get_mut().unwrap().0 += 1;
+    /// assert_eq!(view.upgrade().unwrap().count(), 1);
+    ///
+    /// let upgraded = view.upgrade().unwrap();
+    /// assert!(counter.get_mut().is_none());
+    /// # drop(upgraded);
+    /// ```
+    ///
+    /// Forgetting the guard rather than dropping it forgets the struct along with it: it is never
+    /// dropped, `get_mut` returns `None` from then on, and the views report `ViewError::Borrowed` until
+    /// the `DependentArc` is dropped:
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use dependent_view::{impl_views, ViewError};
+    /// # trait Count { fn count(&self) -> usize; }
+    /// # struct Counter(usize);
+    /// # impl Count for Counter { fn count(&self) -> usize { self.0 } }
+    /// # impl_views!(Counter: dyn Count);
+    /// let mut counter = DependentArc::new(Counter(0));
+    /// let view = counter.view::<dyn Count>();
+    ///
+    /// std::mem::forget(counter.get_mut().unwrap());
+    /// assert_eq!(view.try_upgrade().err(), Some(ViewError::Borrowed));
+    /// assert!(counter.get_mut().is_none());
+    ///
+    /// drop(counter);
+    /// assert_eq!(view.try_upgrade().err(), Some(ViewError::Dropped));
+    /// ```
+    pub fn get_mut(&mut self) -> Option<ItemMut<'_, T>> {
+        // with the struct taken out, no new upgrade can be made while it is borrowed
+        let mut slot = self.shared.slot.write().unwrap_or_else(PoisonError::into_inner);
+        Arc::get_mut(slot.item.as_mut()?)?;
+        slot.borrowed = true;
+        let item = slot.item.take();
+        drop(slot);
+        Some(ItemMut { item, shared: &self.shared })
+    }
+
+    /// Drops the `DependentArc`, waiting for the struct to be dropped along with it. Every view is
+    /// suspended straight away, then this blocks until the references upgraded from them have been
+    /// released, and drops the struct on the calling thread - so `T::drop` has run once this returns
+    /// `Ok`. The views are only invalidated then.
+    ///
+    /// If references are still held once `timeout` has passed, the `DependentArc` is returned instead,
+    /// its views resumed as if this had never been called: no view has been invalidated, and no
+    /// `on_invalidate` callback has run.
+    ///
+    /// References released from `SyncView::with` or `StrictView` borrows wake the owner straight away,
+    /// and while they are the only ones left, the owner sleeps until they are released. Those returned
+    /// by `upgrade` can't notify it when dropped, so while any is held they are checked for with an
+    /// increasing interval of up to a millisecond.
+    ///
+    /// # Warning
+    ///
+    /// A `std::sync::Weak` produced by `SyncView::to_weak` counts as a reference for as long as it is
+    /// held, even if it is never upgraded: this times out while any is around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use dependent_view::impl_views;
+    /// # use std::sync::Arc;
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # use std::thread;
+    /// # use std::time::Duration;
+    /// trait Write { fn write(&self, data: &[u8]); }
+    /// struct Log { closed: Arc<AtomicBool> }
+    /// impl Write for Log { fn write(&self, _: &[u8]) {} }
+    /// impl Drop for Log { fn drop(&mut self) { self.closed.store(true, Ordering::SeqCst); } }
+    /// impl_views!(Log: dyn Write + Send + Sync);
+    ///
+    /// let closed = Arc::new(AtomicBool::new(false));
+    /// let log = DependentArc::new(Log { closed: closed.clone() });
+    /// let view = log.view::<dyn Write + Send + Sync>();
+    /// let writer = view.upgrade().unwrap();
+    ///
+    /// // the writer holds on to the log, which keeps working once the wait times out
+    /// let log = log.drop_and_wait(Duration::from_millis(10)).unwrap_err();
+    /// assert!(!closed.load(Ordering::SeqCst));
+    /// assert!(view.upgrade().is_some());
+    ///
+    /// thread::spawn(move || {
+    ///     writer.write(b"done");
+    ///     thread::sleep(Duration::from_millis(10));
+    /// });
+    /// assert!(log.drop_and_wait(Duration::from_secs(60)).is_ok());
+    /// assert!(closed.load(Ordering::SeqCst));
+    /// ```
+    pub fn drop_and_wait(mut self, timeout: Duration) -> Result<(), DependentArc<T>> {
+        if self.release(Instant::now() + timeout) {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Drops the `DependentArc` like `drop_and_wait`, returning a future resolving once the struct has
+    /// been dropped. Every view is suspended straight away, and once the references upgraded from them
+    /// have been released, the task polling the future drops the struct and invalidates the views with
+    /// `ViewError::Dropped`, as `drop_and_wait` does. If `T::drop` panics, so does the future.
+    ///
+    /// Dropping the future before it resolves drops the `DependentArc` there and then, invalidating the
+    /// views the same way - the struct is then dropped along with the last reference to it.
+    ///
+    /// Like `drop_and_wait`, the task is woken by references released from `SyncView::with` or
+    /// `StrictView` borrows. References returned by `upgrade` can't wake it, so while any is held, a
+    /// single thread shared by every pending future wakes them every millisecond to check again; it
+    /// exits once no future is left waiting for such references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use dependent_view::impl_views;
+    /// # use std::future::Future;
+    /// # use std::pin::pin;
+    /// # use std::sync::Arc;
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # use std::task::{Context, Poll, Wake, Waker};
+    /// # use std::thread::{self, Thread};
+    /// # use std::time::Duration;
+    /// # struct Unpark(Thread);
+    /// # impl Wake for Unpark { fn wake(self: Arc<Self>) { self.0.unpark(); } }
+    /// # fn block_on<F: Future>(future: F) -> F::Output {
+    /// #     let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    /// #     let mut future = pin!(future);
+    /// #     loop {
+    /// #         if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&waker)) { return output; }
+    /// #         thread::park();
+    /// #     }
+    /// # }
+    /// trait Serve { fn serve(&self); }
+    /// struct Socket { closed: Arc<AtomicBool> }
+    /// impl Serve for Socket { fn serve(&self) {} }
+    /// impl Drop for Socket { fn drop(&mut self) { self.closed.store(true, Ordering::SeqCst); } }
+    /// impl_views!(Socket: dyn Serve + Send + Sync);
+    ///
+    /// let closed = Arc::new(AtomicBool::new(false));
+    /// let socket = DependentArc::new(Socket { closed: closed.clone() });
+    /// let connection = socket.view::<dyn Serve + Send + Sync>().upgrade().unwrap();
+    /// thread::spawn(move || {
+    ///     thread::sleep(Duration::from_millis(10));
+    ///     connection.serve();
+    /// });
+    ///
+    /// block_on(socket.drop_async());
+    /// assert!(closed.load(Ordering::SeqCst));
+    /// ```
+    pub fn drop_async(mut self) -> DropAsync<T> {
+        self.shared.tracker.enter();
+        let item = self.detach();
+        DropAsync { owner: Some(self), item: Some(item) }
+    }
+
+    /// Suspends every view, then waits until the `DependentArc` holds the only reference to the
+    /// struct, so dropping it drops the struct. Returns `false`, with the views resumed, if that
+    /// isn't the case by `deadline`.
+    fn release(&mut self, deadline: Instant) -> bool {
+        let item = self.detach();
+        let tracker = &self.shared.tracker;
+        let released = tracker.wait(|| tracker.outstanding(&item, 1, true), Some(deadline));
+        self.attach(item, released);
+        released
+    }
+
+    /// Suspends every view and takes the struct out of the owner, so that the only references left
+    /// are those upgraded beforehand
+    fn detach(&mut self) -> Arc<T> {
+        let mut slot = self.shared.slot.write().unwrap_or_else(PoisonError::into_inner);
+        slot.suspended += 1;
+        slot.item.take().expect("owners hold their struct while they can be borrowed")
+    }
+
+    /// Puts back the struct taken out by `detach`, resuming the views unless it has been `released`
+    /// to be dropped along with the owner
+    fn attach(&mut self, item: Arc<T>, released: bool) {
+        let mut slot = self.shared.slot.write().unwrap_or_else(PoisonError::into_inner);
+        slot.item = Some(item);
+        if !released {
+            slot.suspended -= 1;
+        }
+    }
+
+    /// Produces a view of the underlying struct as a `BoundedSyncView<'a, U>`, for structs that borrow data
+    /// living for `'a`. Unlike `view`, this doesn't require the struct to be `'static`.
+    ///
+    /// Bounded views are not tracked by the `DependentArc`, so they can't be revoked - they are
+    /// invalidated when it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::arc::DependentArc;
+    /// trait Parse { fn remaining(&self) -> &str; }
+    /// struct Parser<'a> { text: &'a str }
+    /// impl<'a> Parse for Parser<'a> { fn remaining(&self) -> &str { self.text } }
+    /// impl_views!(<'a> Parser<'a>: dyn Parse + 'a);
+    ///
+    /// # fn main() {
+    /// let text = String::from("let x = 1;");
+    /// let parser = DependentArc::new(Parser { text: &text });
+    /// let view = parser.bounded_view::<dyn Parse>();
+    /// assert_eq!(view.upgrade().unwrap().remaining(), "let x = 1;");
+    /// # }
+    /// ```
+    ///
+    /// Views can't outlive the data borrowed by the struct:
+    ///
+    /// ```compile_fail
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::arc::DependentArc;
+    /// # trait Parse { fn remaining(&self) -> &str; }
+    /// # struct Parser<'a> { text: &'a str }
+    /// # impl<'a> Parse for Parser<'a> { fn remaining(&self) -> &str { self.text } }
+    /// # impl_views!(<'a> Parser<'a>: dyn Parse + 'a);
+    /// # fn main() {
+    /// let view;
+    /// {
+    ///     let text = String::from("let x = 1;");
+    ///     let parser = DependentArc::new(Parser { text: &text });
+    ///     view = parser.bounded_view::<dyn Parse>(); // error: `text` does not live long enough
+    /// }
+    /// view.upgrade();
+    /// # }
+    /// ```
+    ///
+    /// Nor can such structs be viewed through the `'static` views of `to_view_sync!`:
+    ///
+    /// ```compile_fail
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::arc::DependentArc;
+    /// # trait Parse { fn remaining(&self) -> &str; }
+    /// # struct Parser<'a> { text: &'a str }
+    /// # impl<'a> Parse for Parser<'a> { fn remaining(&self) -> &str { self.text } }
+    /// # fn main() {
+    /// let text = String::from("let x = 1;");
+    /// let parser = DependentArc::new(Parser { text: &text });
+    /// let view = to_view_sync!(parser => dyn Parse); // error: `text` does not live long enough
+    /// # }
+    /// ```
+    pub fn bounded_view<'a, U: ?Sized + 'a>(&self) -> BoundedSyncView<'a, U>
+    where T : ViewAs<U> + Send + Sync + 'a
+    {
+        BoundedSyncView { source: Arc::new(Issuer {
+            owner: self.shared.id,
+            shared: Arc::downgrade(&self.shared),
+            upcast: T::upcast_arc,
+            invalidated: self.shared.invalidated(),
+            callbacks: Default::default()
+        }) }
+    }
+
+    /// Replaces the owned struct with `item`, returning the struct it replaced. Views handed out so far
+    /// upgrade to the new struct from then on, while references upgraded beforehand keep the old one
+    /// alive until they are dropped. The struct can be replaced from any thread sharing the owner.
+    ///
+    /// `item` may be of a different type, as long as it can be viewed as the owned type: the struct of
+    /// a `DependentArc<dyn Trait>` can be replaced with any type registered as a `dyn Trait` with
+    /// `impl_views!`, after which every view of the trait or its supertraits upgrades to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::arc::DependentArc;
+    /// trait Greet { fn greet(&self) -> String; }
+    /// struct English;
+    /// impl Greet for English { fn greet(&self) -> String { "hello".to_string() } }
+    /// struct French;
+    /// impl Greet for French { fn greet(&self) -> String { "bonjour".to_string() } }
+    /// impl_views!(French: dyn Greet + Send + Sync);
+    ///
+    /// # fn main() {
+    /// let greeter : DependentArc<dyn Greet + Send + Sync> = DependentArc::from(Box::new(English) as Box<dyn Greet + Send + Sync>);
+    /// let view = to_view_sync!(greeter => dyn Greet);
+    /// let in_flight = view.upgrade().unwrap();
+    ///
+    /// greeter.replace(French);
+    /// assert_eq!(view.upgrade().unwrap().greet(), "bonjour");
+    /// assert_eq!(in_flight.greet(), "hello");
+    /// # }
+    /// ```
+    pub fn replace<V: ViewAs<T>>(&self, item: V) -> Arc<T> {
+        self.swap(V::upcast_arc(Arc::new(item)))
+    }
+
+    /// Replaces the owned struct with a boxed one, as `replace`, for structs already boxed as the
+    /// owned type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::arc::DependentArc;
+    /// trait Greet { fn greet(&self) -> String; }
+    /// struct English;
+    /// impl Greet for English { fn greet(&self) -> String { "hello".to_string() } }
+    /// struct French;
+    /// impl Greet for French { fn greet(&self) -> String { "bonjour".to_string() } }
+    ///
+    /// # fn main() {
+    /// let greeter : DependentArc<dyn Greet + Send + Sync> = DependentArc::from(Box::new(English) as Box<dyn Greet + Send + Sync>);
+    /// let view = to_view_sync!(greeter => dyn Greet);
+    ///
+    /// greeter.replace_boxed(Box::new(French));
+    /// assert_eq!(view.upgrade().unwrap().greet(), "bonjour");
+    /// # }
+    /// ```
+    pub fn replace_boxed(&self, item: Box<T>) -> Arc<T> {
+        self.swap(Arc::from(item))
+    }
+
+    /// Replaces the owned struct with the result of calling `f` on it, as `replace`, returning the
+    /// struct it replaced.
+    ///
+    /// This is a read-copy-update: `f` runs on the current struct without any lock held, while views
+    /// keep upgrading to it. The result only replaces the struct if no other update or replacement
+    /// happened in the meantime - otherwise `f` runs again, on the newer struct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use std::thread;
+    /// let hits = DependentArc::new(0usize);
+    /// let view = hits.view::<usize>();
+    ///
+    /// thread::scope(|s| {
+    ///     for _ in 0..4 {
+    ///         s.spawn(|| for _ in 0..100 { hits.update(|hits| hits + 1); });
+    ///     }
+    /// });
+    /// assert_eq!(*view.upgrade().unwrap(), 400);
+    /// ```
+    pub fn update<F: FnMut(&T) -> T>(&self, mut f: F) -> Arc<T>
+    where T : Sized
+    {
+        loop {
+            let current = self.shared.item();
+            let item = Arc::new(f(&current));
+            let mut slot = self.shared.slot.write().unwrap_or_else(PoisonError::into_inner);
+            let owned = slot.item.as_mut().expect("owners hold their struct while they can be borrowed");
+            // `current` is still held, so its address can't have been reused by a newer struct
+            if Arc::ptr_eq(owned, &current) {
+                return mem::replace(owned, item);
+            }
+        }
+    }
+
+    /// Points the views at `item` in place of the current struct, returning the latter
+    fn swap(&self, item: Arc<T>) -> Arc<T> {
+        let mut slot = self.shared.slot.write().unwrap_or_else(PoisonError::into_inner);
+        mem::replace(slot.item.as_mut().expect("owners hold their struct while they can be borrowed"), item)
+    }
+}
+
+
+
+impl<T: ?Sized + Send + Sync + 'static> DependentArc<T> {
+    /// Produces a view of the underlying struct as a `SyncView<U>`, for any `U` registered with `impl_views!`.
+    ///
+    /// Unlike the macro, this can be used from generic code, by bounding `T: ViewAs<U>`.
+    pub fn view<U: ?Sized + 'static>(&self) -> SyncView<U>
+    where T : ViewAs<U>
+    {
+        self.into_view_internal_sync(T::upcast_arc)
+    }
+
+    /// Suspends every view handed out by this `DependentArc`, until the returned guard is dropped.
+    ///
+    /// While suspended, views can't be upgraded, but unlike revoked views they become upgradable
+    /// again once resumed. Suspensions nest - views resume once every guard has been dropped.
+    ///
+    /// Once this returns, no upgrade is outstanding: after suspending the views, this blocks until
+    /// every reference upgraded from them beforehand has been released, waiting the same way as
+    /// `drop_and_wait`. No upgrade succeeds until the views resume.
+    ///
+    /// # Warning
+    ///
+    /// References returned by `load` count as upgrades too. Suspending the owner on a thread that
+    /// itself holds a reference to the struct will therefore never return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::arc::DependentArc;
+    /// # use std::thread;
+    /// trait Save { fn save(&self); }
+    /// struct Level;
+    /// impl Save for Level { fn save(&self) {} }
+    ///
+    /// # fn main() {
+    /// let level = DependentArc::new(Level);
+    /// let view = to_view_sync!(level => dyn Save);
+    ///
+    /// let guard = level.suspend();
+    /// let worker = view.clone();
+    /// assert!(thread::spawn(move || worker.upgrade().is_none()).join().unwrap());
+    ///
+    /// drop(guard);
+    /// assert!(view.upgrade().is_some());
+    /// # }
+    /// ```
+    pub fn suspend(&self) -> SuspendGuard {
+        self.suspend_holding(1)
+    }
+
+    /// Suspends every view, then waits until the only references to the struct are the `held` ones
+    /// of its owners
+    fn suspend_holding(&self, held: usize) -> SuspendGuard {
+        self.shared.slot.write().unwrap_or_else(PoisonError::into_inner).suspended += 1;
+        let guard = SuspendGuard { shared: Arc::downgrade(&self.shared) as Weak<dyn Suspend> };
+        // the reference waited on with is one more
+        let (item, tracker) = (self.shared.item(), &self.shared.tracker);
+        tracker.wait(|| tracker.outstanding(&item, held + 1, false), None);
+        guard
+    }
+
+    /// Produces a thread safe view of a field of the underlying struct, as selected by `project`. The
+    /// projection can be of the field's own type, or of a trait it implements.
+    ///
+    /// Projections aren't revoked along with views of the type they project to - only by `revoke_all`, or
+    /// individually through the handle returned by `revocable_project` - and are only available for
+    /// sized structs. As they can be shared between threads, projected
+    /// types must be `Sync` - trait objects need a `+ Sync` bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use dependent_view::ViewError;
+    /// # use std::thread;
+    /// trait Inventory { fn items(&self) -> usize; }
+    /// struct Bag(usize);
+    /// impl Inventory for Bag { fn items(&self) -> usize { self.0 } }
+    /// struct Stats { health: u32 }
+    /// struct Player { inventory: Bag, stats: Stats }
+    ///
+    /// let player = DependentArc::new(Player { inventory: Bag(3), stats: Stats { health: 10 } });
+    /// let inventory = player.project::<dyn Inventory + Sync, _>(|p| &p.inventory);
+    /// let stats = player.project(|p| &p.stats);
+    ///
+    /// let view = inventory.clone();
+    /// thread::spawn(move || assert_eq!(view.upgrade().unwrap().items(), 3)).join().unwrap();
+    /// assert_eq!(stats.upgrade().unwrap().health, 10);
+    ///
+    /// drop(player);
+    /// assert!(inventory.upgrade().is_none());
+    /// assert_eq!(stats.try_upgrade().err(), Some(ViewError::Dropped));
+    /// ```
+    pub fn project<U: ?Sized + Sync + 'static, F: Fn(&T) -> &U + Send + Sync + 'static>(&self, project: F) -> SyncProjection<U>
+    where T : Sized
+    {
+        self.revocable_project(project).0
+    }
+
+    /// Produces a projection as `project`, along with a `ViewHandle` that revokes just that projection
+    /// and its clones. Every projection has a handle of its own, even if it projects the same field
+    /// as another, through the same function or not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// struct Stats { health: u32 }
+    /// fn health(stats: &Stats) -> &u32 { &stats.health }
+    ///
+    /// let stats = DependentArc::new(Stats { health: 10 });
+    /// let (bar, bar_handle) = stats.revocable_project(health);
+    /// let (log, _) = stats.revocable_project(health);
+    ///
+    /// bar_handle.revoke();
+    /// assert!(bar.upgrade().is_none());
+    /// assert_eq!(*log.upgrade().unwrap(), 10);
+    /// ```
+    pub fn revocable_project<U: ?Sized + Sync + 'static, F: Fn(&T) -> &U + Send + Sync + 'static>(&self, project: F) -> (SyncProjection<U>, ViewHandle)
+    where T : Sized
+    {
+        let projector = Arc::new(Projector {
+            shared: Arc::downgrade(&self.shared),
+            project,
+            invalidated: OnceLock::new()
+        });
+        let mut issued = self.shared.issued.lock().unwrap_or_else(PoisonError::into_inner);
+        if issued.len() == issued.capacity() {
+            issued.retain(|issued| issued.issuer.strong_count() > 0);
+        }
+        issued.push(Issued { view_type: ViewType::of::<U>(), projection: true, issuer: Arc::downgrade(&projector) as Weak<dyn Issue> });
+        let handle = ViewHandle { issuer: Arc::downgrade(&projector) as Weak<dyn Issue> };
+        (SyncProjection { projector }, handle)
+    }
+
+    /// Produces a view of type `U` along with a `ViewHandle` that revokes just that view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::arc::DependentArc;
+    /// trait Inspect { fn inspect(&self); }
+    /// struct Model;
+    /// impl Inspect for Model { fn inspect(&self) {} }
+    /// impl_views!(Model: dyn Inspect);
+    ///
+    /// # fn main() {
+    /// let model = DependentArc::new(Model);
+    /// let (tool_view, tool_handle) = model.revocable_view::<dyn Inspect>();
+    /// let other_view = model.view::<dyn Inspect>();
+    ///
+    /// // the tool is detached
+    /// tool_handle.revoke();
+    /// assert!(tool_view.upgrade().is_none());
+    /// assert!(other_view.upgrade().is_some());
+    /// # }
+    /// ```
+    pub fn revocable_view<U: ?Sized + 'static>(&self) -> (SyncView<U>, ViewHandle)
+    where T : ViewAs<U>
+    {
+        let issuer : Arc<Issuer<T, U>> = self.issuer(T::upcast_arc);
+        self.track::<U>(&issuer);
+        let handle = ViewHandle { issuer: Arc::downgrade(&issuer) as Weak<dyn Issue> };
+        (SyncView { source: issuer }, handle)
+    }
+
+    /// Produces a view of type `U` issued into `group`, which is revoked when the group is dropped
+    /// or cleared. Repeated views of the same type issued into the same group share one issuer.
+    pub fn view_in<U: ?Sized + 'static>(&self, group: &ViewGroup) -> SyncView<U>
+    where T : ViewAs<U>
+    {
+        let key = (self.shared.id, TypeId::of::<U>());
+        let mut issuers = group.issuers.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(issuer) = issuers.get(&key).and_then(Weak::upgrade) {
+            if issuer.invalidated().is_none() {
+                let issuer = (issuer as Arc<dyn Any + Send + Sync>).downcast::<Issuer<T, U>>()
+                    .expect("group entries are keyed by the TypeId of their view type");
+                return SyncView { source: issuer };
+            }
+        }
+        let issuer : Arc<Issuer<T, U>> = self.issuer(T::upcast_arc);
+        self.track::<U>(&issuer);
+        if issuers.len() == issuers.capacity() {
+            issuers.retain(|_, issuer| issuer.strong_count() > 0);
+        }
+        issuers.insert(key, Arc::downgrade(&issuer) as Weak<dyn Issue>);
+        SyncView { source: issuer }
+    }
+
+    /// Returns a view of type `U`, if one has already been handed out by this `DependentArc` (and not revoked).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::arc::DependentArc;
+    /// # use std::sync::Arc;
+    /// trait Dance { fn dance(&self); }
+    /// trait Prance { fn prance(&self); }
+    /// struct Dancer;
+    /// impl Dance for Dancer { fn dance(&self) {} }
+    /// impl Prance for Dancer { fn prance(&self) {} }
+    ///
+    /// # fn main() {
+    /// let dancer = DependentArc::new(Dancer);
+    /// assert!(dancer.existing_view::<dyn Dance>().is_none());
+    ///
+    /// let view = to_view_sync!(dancer => dyn Dance);
+    /// let again = dancer.existing_view::<dyn Dance>().unwrap();
+    /// assert!(Arc::ptr_eq(&view.upgrade().unwrap(), &again.upgrade().unwrap()));
+    ///
+    /// assert_eq!(dancer.view_types().count(), 1);
+    /// assert!(dancer.view_types().all(|ty| ty.is::<dyn Dance>()));
+    /// # }
+    /// ```
+    pub fn existing_view<U: ?Sized + 'static>(&self) -> Option<SyncView<U>> {
+        let issuer = self.shared.views.read().unwrap_or_else(PoisonError::into_inner).get(&TypeId::of::<U>())?.issuer.clone();
+        let issuer = (issuer as Arc<dyn Any + Send + Sync>).downcast::<Issuer<T, U>>()
+            .expect("view entries are keyed by the TypeId of their view type");
+        Some(SyncView { source: issuer })
+    }
+
+    /// Lists the types of view that have been handed out by this `DependentArc`, in no particular order.
+    pub fn view_types(&self) -> impl Iterator<Item = ViewType> {
+        let view_types : Vec<ViewType> = self.shared.views.read().unwrap_or_else(PoisonError::into_inner).values().map(|entry| entry.view_type).collect();
+        view_types.into_iter()
+    }
+
+    /// Revokes every view of type `U` handed out so far, including those issued with a `ViewHandle`.
+    /// The `DependentArc` keeps working, and views handed out afterwards can be upgraded as normal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::arc::DependentArc;
+    /// trait Inspect { fn inspect(&self); }
+    /// trait Render { fn render(&self); }
+    /// struct Model;
+    /// impl Inspect for Model { fn inspect(&self) {} }
+    /// impl Render for Model { fn render(&self) {} }
+    ///
+    /// # fn main() {
+    /// let model = DependentArc::new(Model);
+    /// let inspector = to_view_sync!(model => dyn Inspect);
+    /// let renderer = to_view_sync!(model => dyn Render);
+    ///
+    /// model.revoke::<dyn Inspect>();
+    /// assert!(inspector.upgrade().is_none());
+    /// assert!(renderer.upgrade().is_some());
+    ///
+    /// let inspector = to_view_sync!(model => dyn Inspect);
+    /// assert!(inspector.upgrade().is_some());
+    /// # }
+    /// ```
+    pub fn revoke<U: ?Sized + 'static>(&self) {
+        let mut issuers : Vec<Arc<dyn Issue>> = Vec::new();
+        issuers.extend(self.shared.views.write().unwrap_or_else(PoisonError::into_inner).remove(&TypeId::of::<U>()).map(|entry| entry.issuer));
+        self.shared.issued.lock().unwrap_or_else(PoisonError::into_inner).retain(|issued| {
+            if issued.projection || !issued.view_type.is::<U>() {
+                return true;
+            }
+            issuers.extend(issued.issuer.upgrade());
+            false
+        });
+        invalidate(issuers, ViewError::Revoked);
+    }
+
+    /// Tracks an issuer of views handed out outside of the per-type entries, so it can be revoked
+    fn track<U: ?Sized + 'static>(&self, issuer: &Arc<Issuer<T, U>>) {
+        let mut issued = self.shared.issued.lock().unwrap_or_else(PoisonError::into_inner);
+        if issued.len() == issued.capacity() {
+            issued.retain(|issued| issued.issuer.strong_count() > 0);
+        }
+        issued.push(Issued { view_type: ViewType::of::<U>(), projection: false, issuer: Arc::downgrade(issuer) as Weak<dyn Issue> });
+    }
+
+    /// Constructs a new issuer of views of type `U`
+    fn issuer<U: ?Sized + 'static>(&self, upcast: fn(Arc<T>) -> Arc<U>) -> Arc<Issuer<T, U>> {
+        Arc::new(Issuer {
+            owner: self.shared.id,
+            shared: Arc::downgrade(&self.shared),
+            upcast,
+            invalidated: self.shared.invalidated(),
+            callbacks: Default::default()
+        })
+    }
+
+    /// internal hidden function used to produce a SyncView
+    /// # Warn
+    /// This function should only be called through the `to_view_sync!` macro. It is not intended for direct use.
+    /// # Remarks
+    /// This struct works by using the fact that it is possible to upcast an `Arc<Concrete>` to an
+    /// `Arc<Trait>` - the `conversion` passed in by the macro is just such an upcast, so the compiler
+    /// checks that `T` implements the trait. The upcast is stored in an issuer along with a `Weak`
+    /// reference to `item`, through which views of that type are upgraded.
+    /// The issuer for each view type is created once, and shared by all later views of that type.
+    #[doc(hidden)]
+    pub fn into_view_internal_sync<U: ?Sized + 'static>(&self, conversion: fn(Arc<T>) -> Arc<U>) -> SyncView<U> {
+        issue(&self.shared, conversion)
+    }
+
+    #[doc(hidden)]
+    pub fn into_mut_view_internal_sync<U: ?Sized + 'static>(&self, conversion: fn(Arc<T>) -> Arc<T::With<U>>) -> SyncView<T::With<U>>
+    where T : InteriorMut, T::With<U> : 'static
+    {
+        self.into_view_internal_sync(conversion)
+    }
+}
+
+
+
+/// Constructs a DependentArc from a `Arc`, imbuing it with the capability to produce views.
+impl<T: ?Sized> From<Arc<T>> for DependentArc<T> {
+    fn from(item: Arc<T>) -> DependentArc<T> {
+        DependentArc {
+            shared: Arc::new(Shared::new(next_owner_id(), Some(item)))
+        }
+    }
+}
+
+/// Constructs a DependentArc from a `Box`, which allows unsized structs such as trait objects to be owned.
+///
+/// Views can be produced of any supertrait of an owned trait object.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::arc::DependentArc;
+/// trait Entity { fn id(&self) -> usize; }
+/// trait Player: Entity { fn name(&self) -> &str; }
+/// struct Hero;
+/// impl Entity for Hero { fn id(&self) -> usize { 0 } }
+/// impl Player for Hero { fn name(&self) -> &str { "hero" } }
+///
+/// fn spawn() -> Box<dyn Player + Send + Sync> { Box::new(Hero) }
+///
+/// # fn main() {
+/// let player : DependentArc<dyn Player + Send + Sync> = DependentArc::from(spawn());
+/// let entity = to_view_sync!(player => dyn Entity);
+/// let same = to_view_sync!(player => dyn Player + Send + Sync);
+/// assert_eq!(entity.upgrade().unwrap().id(), 0);
+/// assert_eq!(same.upgrade().unwrap().name(), "hero");
+/// # }
+/// ```
+impl<T: ?Sized> From<Box<T>> for DependentArc<T> {
+    fn from(item: Box<T>) -> DependentArc<T> {
+        DependentArc::from(Arc::from(item))
+    }
+}
+
+/// Unwraps the `DependentArc`, returning it's internal `Arc`
+///
+/// Note: This will invalidate all `SyncView<Trait>` views you have constructed from this object.
+impl<T: ?Sized> From<DependentArc<T>> for Arc<T> {
+    fn from(dependent: DependentArc<T>) -> Arc<T> {
+        dependent.load()
+    }
+}
+
+impl<T: ?Sized> Drop for DependentArc<T> {
+    fn drop(&mut self) {
+        self.shared.end(ViewError::Dropped);
+        // dropped without the lock held, in case dropping the struct touches its views
+        let item = self.shared.slot.write().unwrap_or_else(PoisonError::into_inner).item.take();
+        drop(item);
+    }
+}
+
+
+
+/// `LazyArc<T>` is a `DependentArc<T>` to be, produced by `DependentArc::lazy`. It hands out views
+/// straight away, which can't be upgraded until the struct is provided with `set`.
+///
+/// Dropping a `LazyArc` without setting it kills its views for good. Consumers can wait for the
+/// struct to be set through the `Ready` handle returned by `ready`, either blocking or asynchronously.
+///
+/// # Examples
+///
+/// ```
+/// # use dependent_view::arc::DependentArc;
+/// # use dependent_view::impl_views;
+/// # use std::thread;
+/// trait Service { fn call(&self) -> usize; }
+/// struct Database;
+/// impl Service for Database { fn call(&self) -> usize { 42 } }
+/// impl_views!(Database: dyn Service);
+///
+/// let database = DependentArc::<Database>::lazy();
+/// let view = database.view::<dyn Service>();
+/// let ready = database.ready();
+/// assert!(view.upgrade().is_none());
+///
+/// let client = thread::spawn(move || {
+///     assert!(ready.wait());
+///     view.upgrade().unwrap().call()
+/// });
+///
+/// let database = database.set(Database);
+/// assert_eq!(client.join().unwrap(), 42);
+/// # drop(database);
+/// ```
+pub struct LazyArc<T> {
+    shared: Arc<Shared<T>>,
+    fulfil: Fulfil
+}
+
+impl<T: Send + Sync + 'static> LazyArc<T> {
+    /// Produces a view of the struct to be as a `SyncView<U>`, for any `U` registered with `impl_views!`.
+    /// The view can be upgraded once the struct has been set.
+    pub fn view<U: ?Sized + 'static>(&self) -> SyncView<U>
+    where T : ViewAs<U>
+    {
+        issue(&self.shared, T::upcast_arc)
+    }
+
+    /// Returns a `Views` handle, through which views of the struct to be can be produced without the
+    /// `LazyArc`. Views produced after it has been dropped without being set report `ViewError::Expired`.
+    ///
+    /// ```
+    /// # use dependent_view::{impl_views, ViewError};
+    /// # use dependent_view::arc::DependentArc;
+    /// trait Render { fn render(&self); }
+    /// struct Canvas;
+    /// impl Render for Canvas { fn render(&self) {} }
+    /// impl_views!(Canvas: dyn Render + Send + Sync);
+    ///
+    /// let canvas = DependentArc::<Canvas>::lazy();
+    /// let views = canvas.views();
+    /// drop(canvas);
+    /// assert_eq!(views.view::<dyn Render + Send + Sync>().try_upgrade().err(), Some(ViewError::Expired));
+    /// ```
+    pub fn views(&self) -> Views<T> {
+        Shared::views(&self.shared)
+    }
+}
+
+impl<T> LazyArc<T> {
+    /// Returns a handle to wait for the struct to be set with.
+    pub fn ready(&self) -> Ready {
+        Ready { readiness: self.fulfil.0.clone() }
+    }
+
+    /// Provides the struct, turning this into a `DependentArc`. Views handed out so far can be upgraded
+    /// from then on, and waiters on `ready` are woken.
+    pub fn set(self, item: T) -> DependentArc<T> {
+        self.shared.slot.write().unwrap_or_else(PoisonError::into_inner).item = Some(Arc::new(item));
+        self.fulfil.0.settle(true);
+        DependentArc {
+            shared: self.shared.clone()
+        }
+    }
+}
+
+impl<T> Drop for LazyArc<T> {
+    fn drop(&mut self) {
+        if self.shared.slot.read().unwrap_or_else(PoisonError::into_inner).item.is_none() {
+            self.shared.end(ViewError::Expired);
+        }
+    }
+}
+
+
+/// Settles a readiness as unfulfilled if it is dropped before being settled - a `LazyArc` dropped
+/// without being set
+struct Fulfil(Arc<Readiness>);
+
+impl Drop for Fulfil {
+    fn drop(&mut self) {
+        self.0.settle(false);
+    }
+}
+
+/// The outcome of a `LazyArc` being set or a view being closed, along with those waiting for it
+struct Readiness<O = bool> {
+    state: Mutex<ReadyState<O>>,
+    settled: Condvar
+}
+
+struct ReadyState<O> {
+    outcome: Option<O>,
+    wakers: Vec<Waker>
+}
+
+impl<O> Default for Readiness<O> {
+    fn default() -> Readiness<O> {
+        Readiness {
+            state: Mutex::new(ReadyState { outcome: None, wakers: Vec::new() }),
+            settled: Condvar::new()
+        }
+    }
+}
+
+impl<O: Copy> Readiness<O> {
+    /// Records the outcome, unless it has already been settled, waking every waiter
+    fn settle(&self, outcome: O) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.outcome.is_none() {
+            state.outcome = Some(outcome);
+            for waker in state.wakers.drain(..) {
+                waker.wake();
+            }
+            self.settled.notify_all();
+        }
+    }
+
+    /// Blocks until the outcome has been settled
+    fn wait(&self) -> O {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let state = self.settled.wait_while(state, |state| state.outcome.is_none()).unwrap_or_else(PoisonError::into_inner);
+        state.outcome.expect("waited until settled")
+    }
+
+    /// Blocks until the outcome has been settled, or `timeout` has passed
+    fn wait_timeout(&self, timeout: Duration) -> Option<O> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let (state, _) = self.settled.wait_timeout_while(state, timeout, |state| state.outcome.is_none())
+            .unwrap_or_else(PoisonError::into_inner);
+        state.outcome
+    }
+
+    /// Polls for the outcome, registering the task to be woken once it has been settled
+    fn poll(&self, cx: &mut Context<'_>) -> Poll<O> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(outcome) = state.outcome {
+            return Poll::Ready(outcome);
+        }
+        if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+
+/// A handle to wait for a `LazyArc` to be set, as returned by `LazyArc::ready`.
+#[derive(Clone)]
+pub struct Ready {
+    readiness: Arc<Readiness>
+}
+
+impl Ready {
+    /// Blocks until the `LazyArc` has been set, returning `true`, or dropped without being set,
+    /// returning `false`.
+    pub fn wait(&self) -> bool {
+        self.readiness.wait()
+    }
+
+    /// Returns a future resolving once the `LazyArc` has been set, to `true`, or dropped without being
+    /// set, to `false`. The future works with any executor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::arc::DependentArc;
+    /// # use std::future::Future;
+    /// # use std::pin::pin;
+    /// # use std::sync::Arc;
+    /// # use std::task::{Context, Poll, Wake, Waker};
+    /// # use std::thread::{self, Thread};
+    /// # struct Unpark(Thread);
+    /// # impl Wake for Unpark { fn wake(self: Arc<Self>) { self.0.unpark(); } }
+    /// # fn block_on<F: Future>(future: F) -> F::Output {
+    /// #     let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    /// #     let mut future = pin!(future);
+    /// #     loop {
+    /// #         if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&waker)) { return output; }
+    /// #         thread::park();
+    /// #     }
+    /// # }
+    /// let config = DependentArc::<String>::lazy();
+    /// let ready = config.ready();
+    ///
+    /// let loader = thread::spawn(move || config.set(String::from("loaded")));
+    /// assert!(block_on(ready.wait_async()));
+    /// # drop(loader.join());
+    ///
+    /// let abandoned = DependentArc::<String>::lazy();
+    /// let ready = abandoned.ready();
+    /// drop(abandoned);
+    /// assert!(!block_on(ready.wait_async()));
+    /// ```
+    pub fn wait_async(&self) -> WaitReady {
+        WaitReady { readiness: self.readiness.clone() }
+    }
+}
+
+
+/// Future returned by `Ready::wait_async`.
+pub struct WaitReady {
+    readiness: Arc<Readiness>
+}
+
+impl Future for WaitReady {
+    type Output = bool;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        self.readiness.poll(cx)
+    }
+}
+
+
+/// Future returned by `DependentArc::drop_async`, resolving once the struct has been dropped.
+///
+/// Dropping the future before it resolves drops the `DependentArc`, invalidating its views.
+pub struct DropAsync<T: ?Sized> {
+    /// `None` once the owner has been dropped
+    owner: Option<DependentArc<T>>,
+    /// the struct, taken out of the owner while waiting for the references upgraded beforehand
+    item: Option<Arc<T>>
+}
+
+impl<T: ?Sized> DropAsync<T> {
+    /// Stops waiting, and drops the owner along with its reference to the struct
+    fn finish(&mut self) {
+        let (Some(mut owner), Some(item)) = (self.owner.take(), self.item.take()) else {
+            return;
+        };
+        owner.shared.tracker.leave();
+        *owner.shared.tracker.waker.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        owner.attach(item, true);
+    }
+}
+
+impl<T: ?Sized> Future for DropAsync<T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let (owner, item) = match (&self.owner, &self.item) {
+            (Some(owner), Some(item)) => (owner, item),
+            _ => panic!("`DropAsync` polled after it resolved")
+        };
+        let tracker = &owner.shared.tracker;
+        if tracker.poll(cx, || tracker.outstanding(item, 1, true)).is_pending() {
+            return Poll::Pending;
+        }
+        self.finish();
+        Poll::Ready(())
+    }
+}
+
+impl<T: ?Sized> Drop for DropAsync<T> {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// Tasks waiting for references upgraded from views, which can't wake them when released
+struct Recheck {
+    wakers: Vec<Waker>,
+    running: bool
+}
+
+/// Tasks to wake every millisecond, so they check again whether the references they wait for have
+/// been released
+static RECHECK: Mutex<Recheck> = Mutex::new(Recheck { wakers: Vec::new(), running: false });
+
+/// Interval at which waiting owners check again for references which can't notify them
+const RECHECK_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Has the task of `waker` woken after the next interval, starting the thread waking tasks unless
+/// it is running. The thread exits once no task is left waiting.
+fn recheck(waker: &Waker) {
+    let mut recheck = RECHECK.lock().unwrap_or_else(PoisonError::into_inner);
+    if !recheck.wakers.iter().any(|pending| pending.will_wake(waker)) {
+        recheck.wakers.push(waker.clone());
+    }
+    if recheck.running {
+        return;
+    }
+    recheck.running = true;
+    thread::spawn(|| loop {
+        thread::sleep(RECHECK_INTERVAL);
+        let mut recheck = RECHECK.lock().unwrap_or_else(PoisonError::into_inner);
+        if recheck.wakers.is_empty() {
+            recheck.running = false;
+            return;
+        }
+        // tasks still waiting register again when polled
+        let wakers = mem::take(&mut recheck.wakers);
+        drop(recheck);
+        for waker in wakers {
+            waker.wake();
+        }
+    });
+}
+
+
+/// Future returned by `SyncView::closed`, resolving to the reason the view was invalidated.
+///
+/// Dropping the future unregisters it from the view.
+pub struct Closed {
+    readiness: Arc<Readiness<ViewError>>,
+    /// keeps the issuer, and so the callback settling the future, alive
+    source: Arc<dyn Cancel>,
+    key: Option<u64>
+}
+
+impl Future for Closed {
+    type Output = ViewError;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<ViewError> {
+        self.readiness.poll(cx)
+    }
+}
+
+impl Drop for Closed {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.source.cancel(key);
+        }
+    }
+}
+
+
+
+/// `StrictArc<T>` is a variant of `DependentArc<T>` that is the single owner of its struct in
+/// practice, not just by convention - dropping it always drops the struct.
+///
+/// Its views, `StrictView<U>`, can only be borrowed for a short time through a `ViewRef` guard,
+/// which can't be cloned or outlive the view it was borrowed from, and the `StrictArc` only derefs
+/// to the struct itself, so no `Arc` to the struct ever escapes.
+///
+/// Dropping a `StrictArc` suspends its views, then blocks until every outstanding `ViewRef` has
+/// been dropped, so the struct is always dropped on the thread dropping its owner. Dropping the
+/// owner on a thread that itself holds a `ViewRef` to it will therefore never return.
+///
+/// # Examples
+///
+/// ```
+/// # use dependent_view::arc::StrictArc;
+/// # use dependent_view::impl_views;
+/// # use std::sync::Arc;
+/// # use std::sync::atomic::{AtomicBool, Ordering};
+/// # use std::thread;
+/// trait Listen { fn listen(&self); }
+/// struct Listener { dropped: Arc<AtomicBool> }
+/// impl Listen for Listener { fn listen(&self) {} }
+/// impl Drop for Listener { fn drop(&mut self) { self.dropped.store(true, Ordering::SeqCst); } }
+/// impl_views!(Listener: dyn Listen);
+///
+/// let dropped = Arc::new(AtomicBool::new(false));
+/// let listener = StrictArc::new(Listener { dropped: dropped.clone() });
+/// let view = listener.view::<dyn Listen>();
+///
+/// let handle = thread::spawn(move || {
+///     while let Some(guard) = view.borrow() {
+///         guard.listen();
+///     }
+/// });
+///
+/// drop(listener);
+/// assert!(dropped.load(Ordering::SeqCst));
+/// handle.join().unwrap();
+/// ```
+pub struct StrictArc<T> {
+    inner: DependentArc<T>,
+    /// the struct, which is never replaced, held alongside the owner so the `StrictArc` can deref to it
+    item: Arc<T>
+}
+
+impl<T> StrictArc<T> {
+    /// Constructs a `StrictArc` by wrapping an underlying type
+    pub fn new(item: T) -> StrictArc<T> {
+        let inner = DependentArc::new(item);
+        StrictArc { item: inner.load(), inner }
+    }
+
+    /// Revokes every view handed out so far, as `DependentArc::revoke_all`.
+    pub fn revoke_all(&self) {
+        self.inner.revoke_all()
+    }
+}
+
+impl<T: Send + Sync + 'static> StrictArc<T> {
+    /// Produces a view of the underlying struct as a `StrictView<U>`, for any `U` registered with `impl_views!`.
+    pub fn view<U: ?Sized + 'static>(&self) -> StrictView<U>
+    where T : ViewAs<U>
+    {
+        StrictView { view: self.inner.view(), tracker: self.inner.shared.tracker.clone() }
+    }
+
+    /// Produces a view of type `U` issued into `group`, as `DependentArc::view_in`.
+    pub fn view_in<U: ?Sized + 'static>(&self, group: &ViewGroup) -> StrictView<U>
+    where T : ViewAs<U>
+    {
+        StrictView { view: self.inner.view_in(group), tracker: self.inner.shared.tracker.clone() }
+    }
+
+    /// Revokes every view of type `U` handed out so far, as `DependentArc::revoke`.
+    pub fn revoke<U: ?Sized + 'static>(&self) {
+        self.inner.revoke::<U>()
+    }
+
+    /// Suspends every view handed out so far, as `DependentArc::suspend`, waiting until no
+    /// `ViewRef` is held.
+    pub fn suspend(&self) -> SuspendGuard {
+        // the owner and the `StrictArc` itself each hold a reference
+        self.inner.suspend_holding(2)
+    }
+}
+
+impl<T> Deref for StrictArc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.item
+    }
+}
+
+impl<T> Drop for StrictArc<T> {
+    fn drop(&mut self) {
+        // the owner is going away, so the suspension is never lifted
+        self.inner.shared.slot.write().unwrap_or_else(PoisonError::into_inner).suspended += 1;
+        // the owner and the `StrictArc` itself each hold a reference
+        let (item, tracker) = (&self.item, &self.inner.shared.tracker);
+        tracker.wait(|| tracker.outstanding(item, 2, false), None);
+    }
+}
+
+
+/// A view of a struct owned by a `StrictArc`, which can only be borrowed temporarily.
+pub struct StrictView<U: ?Sized> {
+    view: SyncView<U>,
+    tracker: Arc<Tracker>
+}
+
+impl<U: ?Sized> StrictView<U> {
+    /// Borrows the viewed struct, returning `None` if the owning `StrictArc` has been dropped or
+    /// suspended, or the view has been revoked.
+    pub fn borrow(&self) -> Option<ViewRef<'_, U>> {
+        self.try_borrow().ok()
+    }
+
+    /// Borrows the viewed struct, returning the reason it is unavailable if it can't be borrowed.
+    pub fn try_borrow(&self) -> Result<ViewRef<'_, U>, ViewError> {
+        let value = self.view.try_upgrade()?;
+        self.tracker.borrow();
+        Ok(ViewRef { value: Some(value), tracker: &self.tracker })
+    }
+
+    /// Calls `f` with the viewed struct, if it can be borrowed.
+    pub fn with<R, F: FnOnce(&U) -> R>(&self, f: F) -> Option<R> {
+        self.borrow().map(|value| f(&value))
+    }
+}
+
+impl<U: ?Sized> Clone for StrictView<U> {
+    fn clone(&self) -> StrictView<U> {
+        StrictView { view: self.view.clone(), tracker: self.tracker.clone() }
+    }
+}
+
+
+/// A temporary borrow of a struct owned by a `StrictArc`, produced by `StrictView::borrow`.
+pub struct ViewRef<'a, U: ?Sized> {
+    value: Option<Arc<U>>,
+    tracker: &'a Tracker
+}
+
+impl<U: ?Sized> Deref for ViewRef<'_, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        self.value.as_ref().expect("borrow is only released on drop")
+    }
+}
+
+impl<U: ?Sized> Drop for ViewRef<'_, U> {
+    fn drop(&mut self) {
+        self.tracker.release(self.value.take());
     }
 }
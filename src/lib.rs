@@ -1,4 +1,5 @@
 #![allow(dead_code)]
+#![forbid(unsafe_code)]
 
 //! dependent_view is a rust library providing simple wrappers around the `Rc` and `Arc` types, imbuing them with the capability to provide "views" of non-owned structs to separate components of a system. 
 //! 
@@ -16,15 +17,15 @@
 //! 
 //! The library provides two main structs `DependentRc` and `DependentArc` for normal and thread-safe views.
 //!
-//! These change the result of the view type (between `std::rc::Weak` or `std::sync::Weak`).
+//! These change the result of the view type (between `rc::View` or `arc::SyncView`), which mirror `std::rc::Weak` and `std::sync::Weak`.
 //! 
-//! To obtain a `Weak<Trait>` from a dependent struct, use the macros `to_view!()` or `to_view_sync()` respectively.
+//! To obtain a `View<Trait>` from a dependent struct, use the macros `to_view!()` or `to_view_sync()` respectively.
 //! 
 //! The compiler will check at compile time that the type `T` within `DependentRc<T>` impl's the trait you want to obtain a view for. 
 //! 
 //! These dependent types provide a different kind of ownership delegation as compared to standard `Rc`'s or `Box`'s.
 //! 
-//! A `DependentRc` should be viewed as the single owner of it's contained type, however unlike a `Box`, it allows users to generate multiple runtime managed `Weak<Trait>` references to the object (for each `Trait` impl'd by the contained entity) - these `Weak` references cease to be upgradable once the source `DependantRc` is dropped.
+//! A `DependentRc` should be viewed as the single owner of it's contained type, however unlike a `Box`, it allows users to generate multiple runtime managed `View<Trait>` references to the object (for each `Trait` impl'd by the contained entity) - these views cease to be upgradable once the source `DependantRc` is dropped.
 //! 
 //! 
 //! ## Example
@@ -70,15 +71,14 @@
 //! # struct Prancer {id: usize}
 //! # impl Dance for Prancer {fn dance(&self) {println!("D{:?}", self.id);}}
 //! # impl Prance for Prancer {fn prance(&self)  {println!("P{:?}", self.id);}}
-//! let mut dancer = DependentRc::new(Dancer { id: 0 });
-//! let mut prancer = DependentRc::new(Prancer { id: 0 });
+//! let dancer = DependentRc::new(Dancer { id: 0 });
+//! let prancer = DependentRc::new(Prancer { id: 0 });
 //! ```
 //! 
 //! We can use these `DependentRc`'s to create non-owned views of our structs:
 //! 
 //! ```
 //! # #[macro_use] extern crate dependent_view;
-//! # use std::rc::Weak;
 //! # use dependent_view::rc::*;
 //! # trait Dance {
 //! #    fn dance(&self);
@@ -92,20 +92,20 @@
 //! # struct Prancer {id: usize}
 //! # impl Dance for Prancer {fn dance(&self) {println!("D{:?}", self.id);}}
 //! # impl Prance for Prancer {fn prance(&self)  {println!("P{:?}", self.id);}}
-//! # let mut dancer = DependentRc::new(Dancer { id: 0 });
-//! # let mut prancer = DependentRc::new(Prancer { id: 0 });
-//! let dancer_dance_view : Weak<Dance> = to_view!(dancer);
-//! let dancer_prance_view : Weak<Prance> = to_view!(dancer);
+//! # let dancer = DependentRc::new(Dancer { id: 0 });
+//! # let prancer = DependentRc::new(Prancer { id: 0 });
+//! let dancer_dance_view : View<dyn Dance> = to_view!(dancer);
+//! let dancer_prance_view : View<dyn Prance> = to_view!(dancer);
 //! 
-//! let prancer_dance_view : Weak<Dance> = to_view!(prancer);
-//! let prancer_prance_view : Weak<Prance> = to_view!(prancer);
+//! let prancer_dance_view : View<dyn Dance> = to_view!(prancer);
+//! let prancer_prance_view : View<dyn Prance> = to_view!(prancer);
 //! ```
 //! 
 //! We can then share these views to other components, and not have to worry about managing their deletion:
 //! ```
 //! # #[macro_use] extern crate dependent_view;
 //! # use dependent_view::rc::DependentRc;
-//! # use std::rc::{Rc, Weak};
+//! # use dependent_view::rc::View;
 //! # trait Dance {
 //! #    fn dance(&self);
 //! # }
@@ -119,12 +119,12 @@
 //! # impl Dance for Prancer {fn dance(&self) {println!("D{:?}", self.id);}}
 //! # impl Prance for Prancer {fn prance(&self)  {println!("P{:?}", self.id);}}
 //! # pub fn main() {
-//! let mut dancers : Vec<Weak<Dance>> = Vec::new();
-//! let mut prancers : Vec<Weak<Prance>> = Vec::new();
+//! let mut dancers : Vec<View<dyn Dance>> = Vec::new();
+//! let mut prancers : Vec<View<dyn Prance>> = Vec::new();
 //! 
 //! {
-//!     let mut dancer = DependentRc::new(Dancer { id: 0 });
-//!     let mut prancer = DependentRc::new(Prancer { id: 0 });
+//!     let dancer = DependentRc::new(Dancer { id: 0 });
+//!     let prancer = DependentRc::new(Prancer { id: 0 });
 //! 
 //!     dancers.push(to_view!(dancer));
 //!     prancers.push(to_view!(dancer));
@@ -147,16 +147,81 @@
 //! # }
 //! ```
 //! Also, it is a compile time error to attempt to produce a trait view of a struct when the underlying struct doesn't implement the trait:
-//! ```ignore
+//! ```compile_fail
+//! # #[macro_use] extern crate dependent_view;
+//! # use dependent_view::rc::DependentRc;
+//! # use dependent_view::rc::View;
+//! # trait Dance { fn dance(&self); }
+//! # pub fn main() {
 //! struct Bad { id: usize }
 //! let bad = DependentRc::new(Bad { id: 0 });
-//! let bad_view : Weak<Dance> = to_view!(bad); // compile time error
+//! let bad_view : View<dyn Dance> = to_view!(bad); // compile time error
+//! # }
 //! ```
 //! See [`example.rs`](https://github.com/Gopiandcode/dependent-view/blob/master/example.rs) for the full source.
 //!
-//! Due to the way the internals work, if the compiler can not infer the type of the result of `to_view!`,
-//! it complains about `std::mem::transmute` being called on types of different sizes. This usually only
-//! happens if you don't actually use the view - and can often be avoided by simply adding type annotations.
+//! Neither the library nor the macros use any `unsafe` code, so they can be used from crates marked
+//! `#![forbid(unsafe_code)]`:
+//! ```
+//! #![forbid(unsafe_code)]
+//! # #[macro_use] extern crate dependent_view;
+//! # use dependent_view::rc::DependentRc;
+//! # use dependent_view::rc::View;
+//! # trait Dance { fn dance(&self); }
+//! # struct Dancer {id: usize}
+//! # impl Dance for Dancer {fn dance(&self) {println!("D{:?}", self.id);}}
+//! # pub fn main() {
+//! let dancer = DependentRc::new(Dancer { id: 0 });
+//! let view : View<dyn Dance> = to_view!(dancer);
+//! view.upgrade().unwrap().dance();
+//! # }
+//! ```
+//!
+//! If the compiler can not infer the type of the result of `to_view!`, it asks for type annotations.
+//! This usually only happens if you don't actually use the view - and can be avoided by simply adding them.
+//!
+//! ## Generic code
+//! The macros need to know the concrete type `T` to check that it implements the trait, so they can't
+//! be used on an owner of some generic type. For this case, the views a type supports can be
+//! registered with the `impl_views!` macro, which implements the `ViewAs` trait. This gives a bound
+//! that can be named in generic code, and the `view` method on `DependentRc` and `DependentArc`:
+//! ```
+//! # #[macro_use] extern crate dependent_view;
+//! # use dependent_view::rc::{DependentRc, View};
+//! # use dependent_view::ViewAs;
+//! # trait Dance {
+//! #    fn dance(&self);
+//! # }
+//! # trait Prance {
+//! #     fn prance(&self);
+//! # }
+//! # struct Dancer {id: usize}
+//! # impl Dance for Dancer {fn dance(&self) {println!("D{:?}", self.id);}}
+//! # impl Prance for Dancer {fn prance(&self)  {println!("P{:?}", self.id);}}
+//! impl_views!(Dancer: dyn Dance, dyn Prance);
+//!
+//! fn register<T: ViewAs<dyn Dance> + 'static>(owner: &DependentRc<T>, dancers: &mut Vec<View<dyn Dance>>) {
+//!     dancers.push(owner.view::<dyn Dance>());
+//! }
+//!
+//! # pub fn main() {
+//! let mut dancers = Vec::new();
+//! let dancer = DependentRc::new(Dancer { id: 0 });
+//! register(&dancer, &mut dancers);
+//! dancers[0].upgrade().unwrap().dance();
+//! # }
+//! ```
+
+
+use std::any::{self, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 
 #[macro_use]
@@ -167,8 +232,256 @@ pub mod rc;
 pub mod arc;
 
 
-fn push_ref<T>(items: &mut Vec<T>, value: T) -> &T {
-    items.push(value);
-    &items[items.len() - 1]
+pub mod scope;
+
+pub use scope::scope;
+
+
+
+/// Describes a type that views have been handed out as, as listed by `view_types`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ViewType {
+    id: TypeId,
+    name: &'static str
+}
+
+impl ViewType {
+    /// Constructs the `ViewType` for views of type `U`
+    pub fn of<U: ?Sized + 'static>() -> ViewType {
+        ViewType {
+            id: TypeId::of::<U>(),
+            name: any::type_name::<U>()
+        }
+    }
+
+    /// The `TypeId` of the view type, e.g. that of `dyn Trait`
+    pub fn id(&self) -> TypeId {
+        self.id
+    }
+
+    /// The name of the view type, as given by `std::any::type_name`
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns whether this describes views of type `U`
+    pub fn is<U: ?Sized + 'static>(&self) -> bool {
+        self.id == TypeId::of::<U>()
+    }
+}
+
+impl fmt::Debug for ViewType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name)
+    }
 }
 
+
+
+/// The reason a view can't be upgraded, as returned by `try_upgrade`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ViewError {
+    /// The owner of the view has been dropped.
+    Dropped,
+    /// The view has been revoked, on its own or along with other views of its owner.
+    Revoked,
+    /// The owner of the view is suspended, and its views resume once it is no longer.
+    Suspended,
+    /// The owned struct is borrowed mutably through `get_mut`.
+    Borrowed,
+    /// The owner hasn't been given its struct yet - it is lazy and hasn't been set, or still
+    /// being constructed by `new_cyclic`.
+    Uninitialized,
+    /// The owner was lazy and dropped without ever being set, so the view never had a struct.
+    Expired
+}
+
+impl fmt::Display for ViewError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ViewError::Dropped => "the owner of the view has been dropped",
+            ViewError::Revoked => "the view has been revoked",
+            ViewError::Suspended => "the owner of the view is suspended",
+            ViewError::Borrowed => "the viewed struct is borrowed mutably",
+            ViewError::Uninitialized => "the owner of the view has not been initialized yet",
+            ViewError::Expired => "the owner of the view was dropped before being initialized"
+        })
+    }
+}
+
+impl Error for ViewError {}
+
+/// Hasher for maps keyed by `TypeId`, which are already well distributed hashes of the type.
+#[derive(Default)]
+struct TypeIdHasher(u64);
+
+impl Hasher for TypeIdHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = self.0.rotate_left(8) ^ u64::from(*byte);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.0 ^= value;
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A map keyed by the `TypeId` of view types
+type TypeIdMap<V> = HashMap<TypeId, V, BuildHasherDefault<TypeIdHasher>>;
+
+
+/// Hands out a fresh identifier for a `DependentRc` or `DependentArc`
+fn next_owner_id() -> u64 {
+    static NEXT_OWNER_ID : AtomicU64 = AtomicU64::new(0);
+    NEXT_OWNER_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+
+/// Marks that a `Self` owned by a `DependentRc` or `DependentArc` can be viewed as a `U`.
+///
+/// This is the bound behind the `view` methods, allowing views to be produced from generic code.
+/// Rather than implementing it by hand, use the `impl_views!` macro, which checks that the
+/// upcast is valid at compile time.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can not be viewed as `{U}`",
+    label = "no view as `{U}` registered for `{Self}`",
+    note = "register the view with `impl_views!({Self}: {U})` - the type must implement the trait"
+)]
+pub trait ViewAs<U: ?Sized> {
+    /// Upcasts a strong reference to `Self` into one to the view type
+    fn upcast_rc(this: Rc<Self>) -> Rc<U>;
+
+    /// Upcasts a thread safe strong reference to `Self` into one to the view type
+    fn upcast_arc(this: Arc<Self>) -> Arc<U>;
+}
+
+/// Every struct can be viewed as itself, through a view of its concrete type.
+impl<T: ?Sized> ViewAs<T> for T {
+    fn upcast_rc(this: Rc<T>) -> Rc<T> { this }
+    fn upcast_arc(this: Arc<T>) -> Arc<T> { this }
+}
+
+/// Arrays can be viewed as slices of their elements.
+impl<T, const N: usize> ViewAs<[T]> for [T; N] {
+    fn upcast_rc(this: Rc<[T; N]>) -> Rc<[T]> { this }
+    fn upcast_arc(this: Arc<[T; N]>) -> Arc<[T]> { this }
+}
+
+
+/// Macro for registering the views a type supports, implementing `ViewAs` for each of them.
+///
+/// # Error
+/// It is a compile time error to register a view for a trait that the type does not implement.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::arc::DependentArc;
+/// # use dependent_view::arc::SyncView;
+/// trait Dance { fn dance(&self); }
+/// trait Prance { fn prance(&self); }
+///
+/// struct Dancer;
+/// impl Dance for Dancer { fn dance(&self) {} }
+/// impl Prance for Dancer { fn prance(&self) {} }
+///
+/// impl_views!(Dancer: dyn Dance, dyn Prance);
+///
+/// # fn main() {
+/// let dancer = DependentArc::new(Dancer);
+/// let view : SyncView<dyn Prance> = dancer.view::<dyn Prance>();
+/// # view.upgrade().unwrap().prance();
+/// # }
+/// ```
+///
+/// Types borrowing data declare their lifetimes up front, as `impl_views!(<'a> Parser<'a>: dyn Parse + 'a)`.
+#[macro_export]
+macro_rules! impl_views {
+    (<$($lt:lifetime),+> $ty:ty : $($target:ty),+ $(,)*) => {
+        $crate::impl_views!(@each [$($lt),+] $ty : $($target),+);
+    };
+    (@each $lifetimes:tt $ty:ty : $($target:ty),+) => {
+        $( $crate::impl_views!(@lifetimes $lifetimes $ty : $target); )+
+    };
+    (@lifetimes [$($lt:lifetime),+] $ty:ty : $target:ty) => {
+        impl<$($lt),+> $crate::ViewAs<$target> for $ty {
+            fn upcast_rc(this: ::std::rc::Rc<Self>) -> ::std::rc::Rc<$target> { this }
+            fn upcast_arc(this: ::std::sync::Arc<Self>) -> ::std::sync::Arc<$target> { this }
+        }
+    };
+    ($ty:ty : $($target:ty),+ $(,)*) => {
+        $(
+            impl $crate::ViewAs<$target> for $ty {
+                fn upcast_rc(this: ::std::rc::Rc<Self>) -> ::std::rc::Rc<$target> { this }
+                fn upcast_arc(this: ::std::sync::Arc<Self>) -> ::std::sync::Arc<$target> { this }
+            }
+        )+
+    }
+}
+
+
+/// A container providing interior mutability, through which `to_view_mut!` and `to_view_mut_sync!`
+/// produce mutable views: a `DependentRc<RefCell<T>>` produces views of `RefCell<dyn Trait>`, while a
+/// `DependentArc<RwLock<T>>` or `DependentArc<Mutex<T>>` produces views of `RwLock<dyn Trait>` or `Mutex<dyn Trait>`.
+///
+/// The `read` and `write` accessors back the `ReadView` and `WriteView` capability types. Poisoned
+/// locks are treated as if they were not poisoned.
+pub trait InteriorMut {
+    /// The type held by the container
+    type Inner: ?Sized;
+
+    /// The same kind of container, holding a `U` instead
+    type With<U: ?Sized>: ?Sized;
+
+    /// Calls `f` with a shared reference to the contents of the container
+    fn read<R, F: FnOnce(&Self::Inner) -> R>(&self, f: F) -> R;
+
+    /// Calls `f` with a mutable reference to the contents of the container
+    fn write<R, F: FnOnce(&mut Self::Inner) -> R>(&self, f: F) -> R;
+}
+
+impl<T: ?Sized> InteriorMut for RefCell<T> {
+    type Inner = T;
+    type With<U: ?Sized> = RefCell<U>;
+
+    fn read<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+        f(&self.borrow())
+    }
+
+    fn write<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> R {
+        f(&mut self.borrow_mut())
+    }
+}
+
+impl<T: ?Sized> InteriorMut for RwLock<T> {
+    type Inner = T;
+    type With<U: ?Sized> = RwLock<U>;
+
+    fn read<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+        f(&self.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn write<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> R {
+        f(&mut self.write().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl<T: ?Sized> InteriorMut for Mutex<T> {
+    type Inner = T;
+    type With<U: ?Sized> = Mutex<U>;
+
+    fn read<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+        f(&self.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn write<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> R {
+        f(&mut self.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
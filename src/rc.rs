@@ -6,24 +6,35 @@
 //!
 
 
-use super::push_ref;
-
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
+use std::cell::{OnceCell, RefCell};
 use std::convert::*;
-use std::any::Any;
-use std::mem::transmute;
+use super::{next_owner_id, InteriorMut, TypeIdMap, ViewAs, ViewError, ViewType};
+use std::any::{self, Any, TypeId};
+use std::cmp;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::mem;
 use std::ops::{Deref, DerefMut};
+use std::thread;
 
 
 /// Macro for obtaining views from DependentRc
 ///
+/// The macro accepts any place expression evaluating to a `DependentRc` (or a reference to one), and
+/// comes in two forms:
+///  - `to_view!(owner)` infers the trait to view from the type the result is used as.
+///  - `to_view!(owner => dyn Trait)` names the trait inline, so no type annotation is needed.
+///
 /// # Error
 /// It is a compile time error to use this macro to produce a view for a trait that the underlying struct does not implement.
 /// 
 /// # Examples
 ///
 /// ```
-/// # use std::rc::Weak;
+/// # use dependent_view::rc::View;
 /// # #[macro_use] extern crate dependent_view;
 /// # use dependent_view::rc::DependentRc;
 /// struct ExampleStruct {
@@ -41,107 +52,1923 @@ use std::ops::{Deref, DerefMut};
 /// #          }
 /// }
 /// # fn main() {
-/// let mut item : DependentRc<ExampleStruct> = DependentRc::new(ExampleStruct::new());
-/// let view : Weak<ExampleTrait> = to_view!(item);
+/// let item : DependentRc<ExampleStruct> = DependentRc::new(ExampleStruct::new());
+/// let view : View<dyn ExampleTrait> = to_view!(item);
+/// # }
+/// ```
+///
+/// Owners can be reached through arbitrary expressions, and the trait named inline:
+///
+/// ```
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::rc::DependentRc;
+/// trait Dance { fn dance(&self); }
+/// struct Dancer;
+/// impl Dance for Dancer { fn dance(&self) {} }
+///
+/// struct Stage { owner: DependentRc<Dancer>, owners: Vec<DependentRc<Dancer>> }
+///
+/// # fn main() {
+/// let stage = Stage { owner: DependentRc::new(Dancer), owners: vec![DependentRc::new(Dancer)] };
+/// let boxed = Box::new(DependentRc::new(Dancer));
+///
+/// let lead = to_view!(stage.owner => dyn Dance);
+/// let chorus = to_view!(stage.owners[0] => dyn Dance);
+/// let solo = to_view!(*boxed => dyn Dance);
+/// # for view in [lead, chorus, solo].iter() { view.upgrade().unwrap().dance(); }
+/// # }
+/// ```
+///
+/// Naming a trait the struct does not implement is rejected:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::rc::DependentRc;
+/// trait Dance { fn dance(&self); }
+/// struct Wallflower;
+///
+/// # fn main() {
+/// let owner = DependentRc::new(Wallflower);
+/// let view = to_view!(owner => dyn Dance); // error: `Wallflower: Dance` is not satisfied
 /// # }
 /// ```
 #[macro_export]
 macro_rules! to_view {
-    ($dep:tt) => {
-        (unsafe { $dep.into_view_internal::<_, _,_, _, _>(|item| item.clone() as ::std::rc::Rc<_>, |item| ::std::rc::Rc::downgrade(item), |item| unsafe { ::std::mem::transmute(item) })});
-    }
+    ($dep:expr => $target:ty) => {
+        $crate::rc::DependentRc::into_view_internal(&$dep, |item| item as ::std::rc::Rc<$target>)
+    };
+    ($dep:expr) => {
+        $crate::rc::DependentRc::into_view_internal(&$dep, |item| item as ::std::rc::Rc<_>)
+    };
 }
 
 
+/// Macro for obtaining mutable views from a `DependentRc<RefCell<T>>`
+///
+/// Where `to_view!` produces views of `dyn Trait`, which only give consumers `&self` access,
+/// `to_view_mut!(owner => dyn Trait)` produces a `View<RefCell<dyn Trait>>`, so consumers can borrow
+/// the struct mutably as well. The container can be any `InteriorMut`, the view holding the same kind
+/// of container. Like `to_view!`, the trait can also be inferred with `to_view_mut!(owner)`.
+///
+/// To restrict what a consumer can do with the view, wrap it in a `ReadView` or a `WriteView`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::rc::{DependentRc, ReadView, View};
+/// # use std::cell::RefCell;
+/// trait Counter { fn count(&self) -> usize; fn increment(&mut self); }
+/// struct Clicks(usize);
+/// impl Counter for Clicks {
+///     fn count(&self) -> usize { self.0 }
+///     fn increment(&mut self) { self.0 += 1; }
+/// }
+///
+/// # fn main() {
+/// let clicks = DependentRc::new(RefCell::new(Clicks(0)));
+/// let view : View<RefCell<dyn Counter>> = to_view_mut!(clicks => dyn Counter);
+/// view.upgrade().unwrap().borrow_mut().increment();
+///
+/// let display = ReadView::from(to_view_mut!(clicks => dyn Counter));
+/// assert_eq!(display.read(|counter| counter.count()), Some(1));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! to_view_mut {
+    ($dep:expr => $target:ty) => {
+        $crate::rc::DependentRc::into_mut_view_internal::<$target>(&$dep, |item| item as ::std::rc::Rc<_>)
+    };
+    ($dep:expr) => {
+        $crate::rc::DependentRc::into_mut_view_internal(&$dep, |item| item as ::std::rc::Rc<_>)
+    };
+}
+
 
-/// `DependentRc<T>` is a simple wrapper around the `Rc<T>`  type, imbuing it with the capability to provide "views" (`Weak<Trait>`) of non-owned structs to separate components of a system. 
+
+/// `DependentRc<T>` is a simple wrapper around the `Rc<T>`  type, imbuing it with the capability to provide "views" (`View<Trait>`) of non-owned structs to separate components of a system. 
+///
+/// Every view upgrades through a `Weak` reference to the `Rc<T>` retained by the `DependentRc` -
+/// thus when the `DependentRc` is dropped, all of the views are automatically invalidated.
 ///
-/// Internally, it does this by retaining an `Rc<Trait>` for each view you make - thus when the
-/// `DependentRc` is dropped, all of the weak references are automatically invalidated.
-pub struct DependentRc<T> {
+/// Views are issued per type of view handed out: the `DependentRc` keeps one entry per type, keyed
+/// by its `TypeId`, so repeatedly requesting a view of the same trait reuses that entry rather than
+/// growing the owner. Revoking the views of a trait retires its entry - views handed out afterwards
+/// are issued through a fresh one.
+pub struct DependentRc<T: ?Sized> {
     item: Rc<T>,
-    dependants: Vec<Rc<Any>>
+    shared: Rc<Shared<T>>
+}
+
+
+/// A type of view handed out by a `DependentRc`, along with the issuer its views upgrade through
+struct Entry {
+    view_type: ViewType,
+    issuer: Rc<dyn Issue>
+}
+
+/// An issuer of views handed out with a handle or into a group, or of projections, tracked so it can
+/// be revoked with the rest of its kind
+struct Issued {
+    view_type: ViewType,
+    /// whether the issuer issues projections, which are only revoked through their handles
+    projection: bool,
+    issuer: Weak<dyn Issue>
+}
+
+
+/// State of a `DependentRc` shared with the issuers of its views, along with the issuers it has handed out
+struct Shared<T: ?Sized> {
+    id: u64,
+    slot: RefCell<Slot<T>>,
+    views: RefCell<TypeIdMap<Entry>>,
+    issued: RefCell<Vec<Issued>>,
+    /// why the views can no longer be upgraded, once the owner has gone - shared with `Views` handles,
+    /// which can outlive the rest of the state
+    ended: Rc<OnceCell<ViewError>>
+}
+
+/// The reference views are upgraded through, along with whether upgrading is currently allowed
+struct Slot<T: ?Sized> {
+    /// detached while the owned struct is borrowed mutably
+    item: Option<Weak<T>>,
+    borrowed: bool,
+    suspended: usize
+}
+
+impl<T: ?Sized> Shared<T> {
+    /// Constructs the state of the owner numbered `id`, whose views upgrade through `item`
+    fn new(id: u64, item: Option<Weak<T>>) -> Shared<T> {
+        Shared {
+            id,
+            slot: RefCell::new(Slot { item, borrowed: false, suspended: 0 }),
+            views: RefCell::new(TypeIdMap::default()),
+            issued: RefCell::new(Vec::new()),
+            ended: Rc::default()
+        }
+    }
+
+    /// Invalidates every view handed out by the owner so far for `reason`
+    fn invalidate_all(&self, reason: ViewError) {
+        // collected first, so the callbacks of the views can use the owner's state
+        let mut issuers : Vec<Rc<dyn Issue>> = self.views.borrow_mut().drain()
+            .map(|(_, entry)| entry.issuer)
+            .collect();
+        issuers.extend(self.issued.borrow_mut().drain(..).filter_map(|issued| issued.issuer.upgrade()));
+        invalidate(issuers, reason);
+    }
+
+    /// Detaches the views for good as the owner goes away, then invalidates them for `reason`
+    fn end(&self, reason: ViewError) {
+        if self.ended.set(reason).is_err() {
+            return;
+        }
+        self.slot.borrow_mut().item = None;
+        self.invalidate_all(reason);
+    }
+
+    /// Constructs the invalidation state of a new issuer, invalidated already if the owner has ended
+    fn invalidated(&self) -> OnceCell<ViewError> {
+        (*self.ended).clone()
+    }
+
+    /// Constructs a handle producing views through `shared`
+    fn views(shared: &Rc<Shared<T>>) -> Views<T> {
+        Views { id: shared.id, shared: Rc::downgrade(shared), ended: shared.ended.clone() }
+    }
+
+    /// Upgrades the owned struct, unless the owner has been suspended or the struct is unavailable
+    fn try_upgrade(&self) -> Result<Rc<T>, ViewError> {
+        if let Some(reason) = self.ended.get() {
+            return Err(*reason);
+        }
+        let slot = self.slot.borrow();
+        if slot.suspended > 0 {
+            return Err(ViewError::Suspended);
+        }
+        if slot.borrowed {
+            return Err(ViewError::Borrowed);
+        }
+        // the struct is only dropped along with the owner, once it has been set
+        let item = slot.item.as_ref().ok_or(ViewError::Uninitialized)?;
+        item.upgrade().ok_or(ViewError::Dropped)
+    }
+}
+
+/// Invalidates the views of a dropped owner, or expires them if it was lazy and never set. Owners
+/// invalidate their views when dropped, so this only catches views issued while they were.
+impl<T: ?Sized> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let slot = self.slot.get_mut();
+        let reason = *self.ended.get_or_init(|| match slot.item {
+            Some(_) => ViewError::Dropped,
+            None => ViewError::Expired
+        });
+        self.invalidate_all(reason);
+    }
+}
+
+/// Invalidates `issuers` for `reason`, only running their callbacks once every one of them has been,
+/// so that a callback can't upgrade a view about to be invalidated
+fn invalidate(issuers: Vec<Rc<dyn Issue>>, reason: ViewError) {
+    let callbacks : Vec<Callback> = issuers.iter().flat_map(|issuer| issuer.invalidate(reason)).collect();
+    for callback in callbacks {
+        callback(reason);
+    }
+}
+
+/// Type erased resumption of a suspended `DependentRc`
+trait Suspend {
+    fn resume(&self);
+}
+
+impl<T: ?Sized> Suspend for Shared<T> {
+    fn resume(&self) {
+        self.slot.borrow_mut().suspended -= 1;
+    }
+}
+
+
+/// Type erased operations on the issuer of a type of view
+trait Issue: Any {
+    /// Invalidates the views of the issuer for `reason`, unless they have been invalidated already,
+    /// returning the callbacks left to run
+    fn invalidate(&self, reason: ViewError) -> Vec<Callback>;
+    fn invalidated(&self) -> Option<ViewError>;
+}
+
+/// Upgrades views of type `U`, erasing the type of the underlying struct
+trait Source<U: ?Sized>: Cancel {
+    fn try_upgrade(&self) -> Result<Rc<U>, ViewError>;
+    fn subscribe(&self, callback: Callback) -> Option<u64>;
+    fn owner(&self) -> u64;
+    fn is_alive(&self) -> bool;
+}
+
+/// Cancellation of the callbacks registered on an issuer
+trait Cancel {
+    fn cancel(&self, key: u64) -> bool;
+}
+
+/// A callback registered with `on_invalidate`
+type Callback = Box<dyn FnOnce(ViewError)>;
+
+/// Callbacks registered on the views of an issuer, run once they are invalidated
+#[derive(Default)]
+struct Callbacks {
+    next: u64,
+    pending: Vec<(u64, Callback)>
+}
+
+/// Issues the views of type `U` of a `DependentRc<T>`
+struct Issuer<T: ?Sized, U: ?Sized> {
+    owner: u64,
+    shared: Weak<Shared<T>>,
+    upcast: fn(Rc<T>) -> Rc<U>,
+    invalidated: OnceCell<ViewError>,
+    callbacks: RefCell<Callbacks>
+}
+
+impl<T: ?Sized + 'static, U: ?Sized + 'static> Issue for Issuer<T, U> {
+    fn invalidate(&self, reason: ViewError) -> Vec<Callback> {
+        if self.invalidated.set(reason).is_err() {
+            return Vec::new();
+        }
+        // callbacks registered from here on run straight away, so the pending ones are final
+        let pending = mem::take(&mut self.callbacks.borrow_mut().pending);
+        pending.into_iter().map(|(_, callback)| callback).collect()
+    }
+
+    fn invalidated(&self) -> Option<ViewError> {
+        self.invalidated.get().copied()
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Source<U> for Issuer<T, U> {
+    fn try_upgrade(&self) -> Result<Rc<U>, ViewError> {
+        if let Some(reason) = self.invalidated.get() {
+            return Err(*reason);
+        }
+        self.shared.upgrade().ok_or(ViewError::Dropped)?.try_upgrade().map(self.upcast)
+    }
+
+    fn subscribe(&self, callback: Callback) -> Option<u64> {
+        let mut callbacks = self.callbacks.borrow_mut();
+        if let Some(reason) = self.invalidated.get() {
+            drop(callbacks);
+            callback(*reason);
+            return None;
+        }
+        let key = callbacks.next;
+        callbacks.next += 1;
+        callbacks.pending.push((key, callback));
+        Some(key)
+    }
+
+    fn owner(&self) -> u64 {
+        self.owner
+    }
+
+    fn is_alive(&self) -> bool {
+        self.invalidated.get().is_none() && self.shared.strong_count() > 0
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Cancel for Issuer<T, U> {
+    fn cancel(&self, key: u64) -> bool {
+        let mut callbacks = self.callbacks.borrow_mut();
+        let count = callbacks.pending.len();
+        callbacks.pending.retain(|(pending, _)| *pending != key);
+        callbacks.pending.len() < count
+    }
+}
+
+
+/// Upgrades projections to a field of type `U`, erasing the type of the underlying struct
+trait Project<U: ?Sized> {
+    fn try_upgrade(&self) -> Result<Rc<dyn Any>, ViewError>;
+    fn project<'a>(&self, item: &'a dyn Any) -> &'a U;
+}
+
+/// Issues the projections of a `DependentRc<T>` through `F`
+struct Projector<T, F> {
+    shared: Weak<Shared<T>>,
+    project: F,
+    invalidated: OnceCell<ViewError>
+}
+
+impl<T: 'static, F: 'static> Issue for Projector<T, F> {
+    fn invalidate(&self, reason: ViewError) -> Vec<Callback> {
+        let _ = self.invalidated.set(reason);
+        Vec::new()
+    }
+
+    fn invalidated(&self) -> Option<ViewError> {
+        self.invalidated.get().copied()
+    }
+}
+
+impl<T: 'static, U: ?Sized, F: Fn(&T) -> &U> Project<U> for Projector<T, F> {
+    fn try_upgrade(&self) -> Result<Rc<dyn Any>, ViewError> {
+        if let Some(reason) = self.invalidated.get() {
+            return Err(*reason);
+        }
+        self.shared.upgrade().ok_or(ViewError::Dropped)?.try_upgrade().map(|item| item as Rc<dyn Any>)
+    }
+
+    fn project<'a>(&self, item: &'a dyn Any) -> &'a U {
+        (self.project)(item.downcast_ref().expect("projections are only handed their own owner's struct"))
+    }
+}
+
+
+
+/// Issues a view of type `U` through the cached issuer of that type, creating it for the first view
+fn issue<T, U>(shared: &Rc<Shared<T>>, upcast: fn(Rc<T>) -> Rc<U>) -> View<U>
+where T : ?Sized + 'static, U : ?Sized + 'static
+{
+    let issuer = shared.views.borrow_mut()
+        .entry(TypeId::of::<U>())
+        .or_insert_with(|| Entry {
+            view_type: ViewType::of::<U>(),
+            issuer: Rc::new(Issuer { owner: shared.id, shared: Rc::downgrade(shared), upcast, invalidated: shared.invalidated(), callbacks: Default::default() })
+        })
+        .issuer.clone();
+    let issuer = (issuer as Rc<dyn Any>).downcast::<Issuer<T, U>>()
+        .expect("view entries are keyed by the TypeId of their view type");
+    View { source: issuer }
+}
+
+
+/// A view of a struct owned by a `DependentRc`, as produced by `to_view!` or `DependentRc::view`.
+///
+/// Like a `Weak<U>`, a view does not keep the struct alive, and must be upgraded to be used. Views
+/// cease to be upgradable once the owning `DependentRc` is dropped, or the view has been revoked, and
+/// can't be upgraded while the `DependentRc` is suspended.
+pub struct View<U: ?Sized> {
+    source: Rc<dyn Source<U>>
+}
+
+impl<U: ?Sized> View<U> {
+    /// Attempts to upgrade the view to an `Rc<U>`, returning `None` if the owning `DependentRc`
+    /// has been dropped or suspended, or the view has been revoked.
+    pub fn upgrade(&self) -> Option<Rc<U>> {
+        self.source.try_upgrade().ok()
+    }
+
+    /// Attempts to upgrade the view to an `Rc<U>`, returning the reason the view is unavailable if it
+    /// can't be upgraded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::rc::DependentRc;
+    /// # use dependent_view::{impl_views, ViewError};
+    /// trait Plugin { fn run(&self); }
+    /// struct Formatter;
+    /// impl Plugin for Formatter { fn run(&self) {} }
+    /// impl_views!(Formatter: dyn Plugin);
+    ///
+    /// let mut formatter = DependentRc::new(Formatter);
+    /// let (plugin, handle) = formatter.revocable_view::<dyn Plugin>();
+    /// let other = formatter.view::<dyn Plugin>();
+    /// assert!(plugin.try_upgrade().is_ok());
+    ///
+    /// let suspended = formatter.suspend();
+    /// assert_eq!(plugin.try_upgrade().err(), Some(ViewError::Suspended));
+    /// drop(suspended);
+    ///
+    /// let borrowed = formatter.get_mut().unwrap();
+    /// assert_eq!(plugin.try_upgrade().err(), Some(ViewError::Borrowed));
+    /// drop(borrowed);
+    ///
+    /// handle.revoke();
+    /// assert_eq!(plugin.try_upgrade().err(), Some(ViewError::Revoked));
+    ///
+    /// drop(formatter);
+    /// assert_eq!(other.try_upgrade().err(), Some(ViewError::Dropped));
+    /// assert_eq!(plugin.try_upgrade().err(), Some(ViewError::Revoked));
+    /// ```
+    pub fn try_upgrade(&self) -> Result<Rc<U>, ViewError> {
+        self.source.try_upgrade()
+    }
+
+    /// Upgrades the view for the duration of `f`, returning `None` without calling it if the
+    /// view can't be upgraded.
+    pub fn with<R, F: FnOnce(&U) -> R>(&self, f: F) -> Option<R> {
+        self.upgrade().map(|item| f(&item))
+    }
+
+    /// Returns whether the view may still be upgraded: `false` once the owning `DependentRc` has been
+    /// dropped or the view has been revoked. A view of a suspended or borrowed owner is still alive.
+    pub fn is_alive(&self) -> bool {
+        self.source.is_alive()
+    }
+
+    /// Registers `callback` to run once the view is invalidated - when the owning `DependentRc` is dropped
+    /// or the view is revoked - with the reason it was. If the view already has been, `callback` runs
+    /// straight away.
+    ///
+    /// The callback runs exactly once, unless it is cancelled through the returned handle first.
+    /// Dropping the handle leaves it registered, while dropping every clone of the view may drop it
+    /// without running.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::rc::{DependentRc, View};
+    /// # use dependent_view::{impl_views, ViewError};
+    /// # use std::cell::RefCell;
+    /// # use std::collections::HashMap;
+    /// # use std::rc::Rc;
+    /// trait Model { fn name(&self) -> String; }
+    /// struct Document;
+    /// impl Model for Document { fn name(&self) -> String { String::from("document") } }
+    /// impl_views!(Document: dyn Model);
+    ///
+    /// // a cache of rendered models, evicting those that go away
+    /// let cache : Rc<RefCell<HashMap<View<dyn Model>, String>>> = Rc::default();
+    /// let document = DependentRc::new(Document);
+    /// let model = document.view::<dyn Model>();
+    /// cache.borrow_mut().insert(model.clone(), model.with(|model| model.name()).unwrap());
+    ///
+    /// let evict = cache.clone();
+    /// let key = model.clone();
+    /// let handle = model.on_invalidate(move |reason| {
+    ///     assert_eq!(reason, ViewError::Dropped);
+    ///     evict.borrow_mut().remove(&key);
+    /// });
+    ///
+    /// drop(document);
+    /// assert!(cache.borrow().is_empty());
+    /// assert!(!handle.cancel());
+    /// ```
+    pub fn on_invalidate<F: FnOnce(ViewError) + 'static>(&self, callback: F) -> CallbackHandle {
+        let key = self.source.subscribe(Box::new(callback));
+        CallbackHandle { source: Rc::downgrade(&self.source) as Weak<dyn Cancel>, key }
+    }
+
+    /// Returns the id of the owning `DependentRc`, as returned by `DependentRc::id`.
+    pub fn owner_id(&self) -> u64 {
+        self.source.owner()
+    }
+
+    /// Returns whether both views were handed out by the same `DependentRc`, whatever their types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::{DependentRc, View};
+    /// # use std::collections::HashSet;
+    /// trait Dance { fn dance(&self); }
+    /// trait Prance { fn prance(&self); }
+    /// struct Dancer;
+    /// impl Dance for Dancer { fn dance(&self) {} }
+    /// impl Prance for Dancer { fn prance(&self) {} }
+    /// impl_views!(Dancer: dyn Dance, dyn Prance);
+    ///
+    /// # fn main() {
+    /// let first = DependentRc::new(Dancer);
+    /// let second = DependentRc::new(Dancer);
+    ///
+    /// let dance = first.view::<dyn Dance>();
+    /// assert!(dance.same_owner(&first.view::<dyn Prance>()));
+    /// assert!(!dance.same_owner(&second.view::<dyn Prance>()));
+    ///
+    /// // views of a type handed out by an owner are equal, so registering them twice has no effect
+    /// let mut dancers : HashSet<View<dyn Dance>> = HashSet::new();
+    /// assert!(dancers.insert(dance));
+    /// assert!(!dancers.insert(first.view::<dyn Dance>()));
+    /// assert!(dancers.insert(second.view::<dyn Dance>()));
+    ///
+    /// // while a revocable view is a view of its own
+    /// let (revocable, _handle) = first.revocable_view::<dyn Dance>();
+    /// assert!(dancers.insert(revocable.clone()));
+    /// assert!(revocable.same_owner(&first.view::<dyn Dance>()));
+    /// # }
+    /// ```
+    pub fn same_owner<V: ?Sized>(&self, other: &View<V>) -> bool {
+        self.owner_id() == other.owner_id()
+    }
+
+    /// Returns the address of the source the view upgrades through, which identifies it
+    fn address(&self) -> usize {
+        Rc::as_ptr(&self.source).cast::<()>().addr()
+    }
+
+    /// Converts the view to a `std::rc::Weak`, or `None` if it can't currently be upgraded.
+    ///
+    /// The returned `Weak` only dies with the struct itself: it is not affected by revoking or
+    /// suspending the view, and keeps upgrading while references upgraded from the view are held.
+    ///
+    /// # Warning
+    ///
+    /// The `Weak` is not tracked by the `DependentRc`, which has to assume it may be upgraded at any
+    /// time: for as long as it is held, `DependentRc::get_mut` returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::rc::DependentRc;
+    /// # use dependent_view::impl_views;
+    /// # trait Count { fn count(&self) -> usize; }
+    /// # struct Counter(usize);
+    /// # impl Count for Counter { fn count(&self) -> usize { self.0 } }
+    /// # impl_views!(Counter: dyn Count);
+    /// let mut counter = DependentRc::new(Counter(0));
+    /// let weak = counter.view::<dyn Count>().to_weak().unwrap();
+    ///
+    /// // the owner can't tell the `Weak` won't be upgraded while it is mutating the struct
+    /// assert!(counter.get_mut().is_none());
+    /// drop(weak);
+    /// assert!(counter.get_mut().is_some());
+    /// ```
+    pub fn to_weak(&self) -> Option<Weak<U>> {
+        self.upgrade().map(|item| Rc::downgrade(&item))
+    }
+}
+
+impl<U: ?Sized> Clone for View<U> {
+    fn clone(&self) -> View<U> {
+        View { source: self.source.clone() }
+    }
+}
+
+/// Views are equal if they upgrade through the same source: views of a type handed out by a `DependentRc`
+/// are equal, while each revocable view, or view issued into a group, is only equal to its clones.
+/// `same_owner` compares the owners of views instead.
+impl<U: ?Sized> PartialEq for View<U> {
+    fn eq(&self, other: &View<U>) -> bool {
+        Rc::ptr_eq(&self.source, &other.source)
+    }
+}
+
+impl<U: ?Sized> Eq for View<U> {}
+
+impl<U: ?Sized> Hash for View<U> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address().hash(state);
+    }
+}
+
+/// Views are ordered by the address of their source, which is arbitrary but consistent with `Eq`.
+impl<U: ?Sized> PartialOrd for View<U> {
+    fn partial_cmp(&self, other: &View<U>) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<U: ?Sized> Ord for View<U> {
+    fn cmp(&self, other: &View<U>) -> cmp::Ordering {
+        self.address().cmp(&other.address())
+    }
+}
+
+impl<U: ?Sized> fmt::Debug for View<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("View")
+            .field("owner", &self.owner_id())
+            .field("type", &any::type_name::<U>())
+            .field("alive", &self.is_alive())
+            .finish()
+    }
+}
+
+
+/// A view of a struct owned by a `DependentRc` that borrows data, as produced by `DependentRc::bounded_view`.
+///
+/// Bounded views behave like `View`s, but carry the lifetime `'a` of the data the struct borrows,
+/// so they can't outlive it.
+///
+/// # Limitations
+///
+/// As their type needn't be `'static`, bounded views aren't tracked by their owner the way other views
+/// are. They stop upgrading once the owner is dropped, and can't be upgraded while it is borrowed, but
+/// `revoke`, `revoke_all` and `ViewGroup`s don't affect them - so `try_upgrade` never reports
+/// `ViewError::Revoked` - and they have no `on_invalidate` or identity of their own. Owners
+/// of structs that aren't `'static` can't be suspended or projected either, as `suspend` and `project`
+/// require a `'static` struct.
+///
+/// ```
+/// # use dependent_view::{impl_views, ViewError};
+/// # use dependent_view::rc::DependentRc;
+/// trait Parse { fn remaining(&self) -> &str; }
+/// struct Parser<'a> { text: &'a str }
+/// impl<'a> Parse for Parser<'a> { fn remaining(&self) -> &str { self.text } }
+/// impl_views!(<'a> Parser<'a>: dyn Parse + 'a);
+///
+/// let text = String::from("let x = 1;");
+/// let parser = DependentRc::new(Parser { text: &text });
+/// let view = parser.bounded_view::<dyn Parse>();
+///
+/// parser.revoke_all();
+/// assert!(view.upgrade().is_some());
+/// drop(parser);
+/// assert_eq!(view.try_upgrade().err(), Some(ViewError::Dropped));
+/// ```
+pub struct BoundedView<'a, U: ?Sized> {
+    source: Rc<dyn Source<U> + 'a>
+}
+
+impl<U: ?Sized> BoundedView<'_, U> {
+    /// Attempts to upgrade the view to an `Rc<U>`, returning `None` if the owning `DependentRc`
+    /// has been dropped or suspended.
+    pub fn upgrade(&self) -> Option<Rc<U>> {
+        self.source.try_upgrade().ok()
+    }
+
+    /// Attempts to upgrade the view to an `Rc<U>`, returning the reason the view is unavailable if it
+    /// can't be upgraded.
+    pub fn try_upgrade(&self) -> Result<Rc<U>, ViewError> {
+        self.source.try_upgrade()
+    }
+}
+
+impl<'a, U: ?Sized> Clone for BoundedView<'a, U> {
+    fn clone(&self) -> BoundedView<'a, U> {
+        BoundedView { source: self.source.clone() }
+    }
+}
+
+
+/// A view of a field of a struct owned by a `DependentRc`, as produced by `DependentRc::project`.
+///
+/// Projections are upgradable exactly as long as the other views of their owner.
+pub struct Projection<U: ?Sized> {
+    projector: Rc<dyn Project<U>>
+}
+
+impl<U: ?Sized> Projection<U> {
+    /// Attempts to upgrade the projection, returning `None` if the owning `DependentRc` has been
+    /// dropped or suspended, or the projection has been revoked.
+    pub fn upgrade(&self) -> Option<Projected<U>> {
+        self.try_upgrade().ok()
+    }
+
+    /// Attempts to upgrade the projection, returning the reason it is unavailable if it can't be upgraded.
+    pub fn try_upgrade(&self) -> Result<Projected<U>, ViewError> {
+        let item = self.projector.try_upgrade()?;
+        Ok(Projected { item, projector: self.projector.clone() })
+    }
+}
+
+impl<U: ?Sized> Clone for Projection<U> {
+    fn clone(&self) -> Projection<U> {
+        Projection { projector: self.projector.clone() }
+    }
+}
+
+
+/// An upgraded `Projection`, which keeps the owned struct alive and dereferences to the projected field.
+pub struct Projected<U: ?Sized> {
+    item: Rc<dyn Any>,
+    projector: Rc<dyn Project<U>>
+}
+
+impl<U: ?Sized> Deref for Projected<U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        self.projector.project(&*self.item)
+    }
+}
+
+impl<U: ?Sized> Clone for Projected<U> {
+    fn clone(&self) -> Projected<U> {
+        Projected { item: self.item.clone(), projector: self.projector.clone() }
+    }
+}
+
+
+/// A read only capability for a mutable view, given to consumers that must not modify the struct.
+///
+/// Produced from a `View<C>` of an `InteriorMut` container `C`, such as those produced by
+/// `to_view_mut!`, or from a `WriteView` with `read_only`.
+pub struct ReadView<C: ?Sized> {
+    view: View<C>
+}
+
+impl<C: ?Sized + InteriorMut> ReadView<C> {
+    /// Calls `f` with a shared reference to the viewed struct, returning `None` if the view can't be
+    /// upgraded.
+    ///
+    /// # Panics
+    /// Panics if the struct is currently borrowed mutably.
+    pub fn read<R, F: FnOnce(&C::Inner) -> R>(&self, f: F) -> Option<R> {
+        self.view.upgrade().map(|cell| cell.read(f))
+    }
+}
+
+impl<C: ?Sized> Clone for ReadView<C> {
+    fn clone(&self) -> ReadView<C> {
+        ReadView { view: self.view.clone() }
+    }
+}
+
+impl<C: ?Sized> From<View<C>> for ReadView<C> {
+    fn from(view: View<C>) -> ReadView<C> {
+        ReadView { view }
+    }
+}
+
+
+/// A writable capability for a mutable view, which can be narrowed to a `ReadView`.
+///
+/// Produced from a `View<C>` of an `InteriorMut` container `C`, such as those produced by `to_view_mut!`.
+pub struct WriteView<C: ?Sized> {
+    view: View<C>
+}
+
+impl<C: ?Sized + InteriorMut> WriteView<C> {
+    /// Calls `f` with a shared reference to the viewed struct, returning `None` if the view can't be
+    /// upgraded.
+    ///
+    /// # Panics
+    /// Panics if the struct is currently borrowed mutably.
+    pub fn read<R, F: FnOnce(&C::Inner) -> R>(&self, f: F) -> Option<R> {
+        self.view.upgrade().map(|cell| cell.read(f))
+    }
+
+    /// Calls `f` with a mutable reference to the viewed struct, returning `None` if the view can't be
+    /// upgraded.
+    ///
+    /// # Panics
+    /// Panics if the struct is currently borrowed.
+    pub fn write<R, F: FnOnce(&mut C::Inner) -> R>(&self, f: F) -> Option<R> {
+        self.view.upgrade().map(|cell| cell.write(f))
+    }
+}
+
+impl<C: ?Sized> WriteView<C> {
+    /// Produces a read only capability for the same view
+    pub fn read_only(&self) -> ReadView<C> {
+        ReadView { view: self.view.clone() }
+    }
+}
+
+impl<C: ?Sized> Clone for WriteView<C> {
+    fn clone(&self) -> WriteView<C> {
+        WriteView { view: self.view.clone() }
+    }
+}
+
+impl<C: ?Sized> From<View<C>> for WriteView<C> {
+    fn from(view: View<C>) -> WriteView<C> {
+        WriteView { view }
+    }
+}
+
+
+/// A handle a struct owned by a `DependentRc` can keep to produce views of itself, as passed to the
+/// closure of `DependentRc::new_cyclic` or returned by `DependentRc::views`.
+///
+/// The handle doesn't keep the owner alive - views produced after the owner has been dropped can
+/// never be upgraded.
+pub struct Views<T: ?Sized> {
+    id: u64,
+    shared: Weak<Shared<T>>,
+    ended: Rc<OnceCell<ViewError>>
 }
 
+impl<T: ?Sized + 'static> Views<T> {
+    /// Produces a view of the struct as a `View<U>`, for any `U` registered with `impl_views!`. Views
+    /// produced while the struct is being constructed can be upgraded once it has been.
+    pub fn view<U: ?Sized + 'static>(&self) -> View<U>
+    where T : ViewAs<U>
+    {
+        match self.shared.upgrade() {
+            Some(shared) => issue(&shared, T::upcast_rc),
+            None => {
+                let issuer = Rc::new(Issuer { owner: self.id, shared: self.shared.clone(), upcast: T::upcast_rc, invalidated: OnceCell::new(), callbacks: Default::default() });
+                // the state records why the owner ended as it goes, so only a struct whose construction
+                // panicked leaves it unset
+                let _ = issuer.invalidated.set(self.ended.get().copied().unwrap_or(ViewError::Expired));
+                View { source: issuer }
+            }
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for Views<T> {
+    fn clone(&self) -> Views<T> {
+        Views { id: self.id, shared: self.shared.clone(), ended: self.ended.clone() }
+    }
+}
+
+
+/// A token that revokes one specific view (and its clones), as produced by `DependentRc::revocable_view`
+/// and `DependentRc::revocable_project`.
+///
+/// Once revoked, the view can no longer be upgraded, while other views of the same `DependentRc`
+/// are unaffected.
+#[derive(Clone)]
+pub struct ViewHandle {
+    issuer: Weak<dyn Issue>
+}
+
+impl ViewHandle {
+    /// Revokes the view this handle was issued with
+    pub fn revoke(&self) {
+        if let Some(issuer) = self.issuer.upgrade() {
+            invalidate(vec![issuer], ViewError::Revoked);
+        }
+    }
+
+    /// Returns whether the view has been revoked. Views that have been dropped count as revoked.
+    pub fn is_revoked(&self) -> bool {
+        self.issuer.upgrade().is_none_or(|issuer| issuer.invalidated().is_some())
+    }
+}
+
+
+/// A handle to a callback registered with `View::on_invalidate`, through which it can be cancelled.
+pub struct CallbackHandle {
+    source: Weak<dyn Cancel>,
+    /// `None` if the callback ran on registration
+    key: Option<u64>
+}
+
+impl CallbackHandle {
+    /// Cancels the callback, returning whether it was still pending - `false` if it has already run.
+    pub fn cancel(&self) -> bool {
+        match (self.source.upgrade(), self.key) {
+            (Some(source), Some(key)) => source.cancel(key),
+            _ => false
+        }
+    }
+}
 
 
-impl<T> DependentRc<T> {
+/// A group of views, handed out by any number of `DependentRc`s through `DependentRc::view_in`.
+///
+/// Dropping the group, or calling `clear`, revokes every view issued into it, while the owners
+/// live on. Views issued into the group again after a `clear` can be upgraded as normal.
+///
+/// # Examples
+///
+/// ```
+/// # use dependent_view::rc::{DependentRc, ViewGroup};
+/// # use dependent_view::impl_views;
+/// trait Render { fn render(&self); }
+/// struct Sprite;
+/// impl Render for Sprite { fn render(&self) {} }
+/// impl_views!(Sprite: dyn Render);
+///
+/// let sprites = vec![DependentRc::new(Sprite), DependentRc::new(Sprite)];
+///
+/// let renderer = ViewGroup::new();
+/// let views : Vec<_> = sprites.iter().map(|sprite| sprite.view_in::<dyn Render>(&renderer)).collect();
+/// let other = sprites[0].view::<dyn Render>();
+///
+/// // the renderer is torn down
+/// drop(renderer);
+/// assert!(views.iter().all(|view| view.upgrade().is_none()));
+/// assert!(other.upgrade().is_some());
+/// ```
+#[derive(Default)]
+pub struct ViewGroup {
+    issuers: RefCell<HashMap<(u64, TypeId), Weak<dyn Issue>>>
+}
+
+impl ViewGroup {
+    /// Constructs an empty `ViewGroup`
+    pub fn new() -> ViewGroup {
+        ViewGroup::default()
+    }
+
+    /// Revokes every view issued into the group so far
+    pub fn clear(&self) {
+        let issuers : Vec<_> = self.issuers.borrow_mut().drain()
+            .filter_map(|(_, issuer)| issuer.upgrade())
+            .collect();
+        invalidate(issuers, ViewError::Revoked);
+    }
+}
+
+impl Drop for ViewGroup {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+
+/// Guard returned by `DependentRc::suspend`. The views of the `DependentRc` resume once every
+/// guard has been dropped.
+#[must_use = "the views of the owner resume as soon as the guard is dropped"]
+pub struct SuspendGuard {
+    shared: Weak<dyn Suspend>
+}
+
+impl Drop for SuspendGuard {
+    fn drop(&mut self) {
+        if let Some(shared) = self.shared.upgrade() {
+            shared.resume();
+        }
+    }
+}
+
+
+
+/// Guard returned by `DependentRc::get_mut`, giving mutable access to the owned struct. The views of
+/// the `DependentRc` can't be upgraded while it is held - nor once it has been forgotten, until the
+/// struct is borrowed mutably again.
+pub struct ItemMut<'a, T: ?Sized> {
+    item: &'a mut Rc<T>,
+    shared: &'a Shared<T>
+}
+
+impl<T: ?Sized> Deref for ItemMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.item
+    }
+}
+
+impl<T: ?Sized> DerefMut for ItemMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        Rc::get_mut(self.item).expect("views are detached while the struct is borrowed mutably")
+    }
+}
+
+impl<T: ?Sized> Drop for ItemMut<'_, T> {
+    fn drop(&mut self) {
+        let mut slot = self.shared.slot.borrow_mut();
+        slot.item = Some(Rc::downgrade(self.item));
+        slot.borrowed = false;
+    }
+}
+
+
+impl<T: ?Sized> DependentRc<T> {
     /// Constructs a `DependentRc` by wrapping an underlying type
-    pub fn new(item: T) -> DependentRc<T> {
-        DependentRc {
-            item: Rc::new(item),
-            dependants: Vec::new()
+    pub fn new(item: T) -> DependentRc<T>
+    where T : Sized
+    {
+        DependentRc::from(Rc::new(item))
+    }
+
+    /// Constructs a `LazyRc`, which hands out views before the struct they view exists.
+    pub fn lazy() -> LazyRc<T>
+    where T : Sized
+    {
+        LazyRc {
+            shared: Rc::new(Shared::new(next_owner_id(), None))
+        }
+    }
+
+    /// Constructs a `DependentRc` of a struct that holds views of itself, in the manner of `Rc::new_cyclic`.
+    ///
+    /// `build` is passed a `Views` handle, through which it can produce views of the struct before it
+    /// exists - they can be upgraded once `build` has returned. The struct can keep the handle to
+    /// produce more views of itself later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::rc::{DependentRc, View, Views};
+    /// # use dependent_view::impl_views;
+    /// trait Listener { fn notify(&self) -> &str; }
+    /// struct Widget { name: String, views: Views<Widget>, listeners: Vec<View<dyn Listener>> }
+    /// impl Listener for Widget { fn notify(&self) -> &str { &self.name } }
+    /// impl_views!(Widget: dyn Listener);
+    ///
+    /// impl Widget {
+    ///     fn listener(&self) -> View<dyn Listener> {
+    ///         self.views.view::<dyn Listener>()
+    ///     }
+    /// }
+    ///
+    /// let widget = DependentRc::new_cyclic(|views| Widget {
+    ///     name: String::from("button"),
+    ///     listeners: vec![views.view::<dyn Listener>()],
+    ///     views: views.clone()
+    /// });
+    ///
+    /// assert_eq!(widget.listeners[0].upgrade().unwrap().notify(), "button");
+    /// let later = widget.listener();
+    /// drop(widget);
+    /// assert!(later.upgrade().is_none());
+    /// ```
+    pub fn new_cyclic<F: FnOnce(&Views<T>) -> T>(build: F) -> DependentRc<T>
+    where T : Sized
+    {
+        let shared = Rc::new(Shared::new(next_owner_id(), None));
+        let views = Shared::views(&shared);
+        let item = Rc::new(build(&views));
+        shared.slot.borrow_mut().item = Some(Rc::downgrade(&item));
+        DependentRc { item, shared }
+    }
+
+    /// Returns the id of the `DependentRc`, shared by every view it hands out. Ids are unique across
+    /// owners for the lifetime of the process, so they can be used to tell owners apart in logs.
+    pub fn id(&self) -> u64 {
+        self.shared.id
+    }
+
+    /// Returns a `Views` handle, through which views of the struct can be produced without the owner.
+    pub fn views(&self) -> Views<T> {
+        Shared::views(&self.shared)
+    }
+
+    /// Revokes every view handed out so far. The `DependentRc` keeps working, and views handed out
+    /// afterwards can be upgraded as normal.
+    pub fn revoke_all(&self) {
+        self.shared.invalidate_all(ViewError::Revoked);
+    }
+
+    /// Invalidates every view for good, as if the `DependentRc` had been dropped, for scopes ending while
+    /// the owner is still referenced
+    pub(crate) fn end(&self) {
+        self.shared.end(ViewError::Dropped);
+    }
+
+    /// Returns mutable access to the owned struct, or `None` if a consumer currently holds an upgraded
+    /// reference to it. Views handed out so far don't prevent this, but can't be upgraded until the
+    /// returned guard has been dropped.
+    ///
+    /// A `std::rc::Weak` produced by `View::to_weak` counts as an upgraded reference for as long as it is
+    /// held, even if it is never upgraded.
+    ///
+    /// This returns a guard rather than a `&mut T` as the views upgrade through a `Weak` reference to
+    /// the struct, which would make `Rc::get_mut` fail: the guard detaches it for as long as the struct
+    /// is borrowed, and reattaches it when dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::rc::DependentRc;
+    /// # use dependent_view::impl_views;
+    /// # trait Count { fn count(&self) -> usize; }
+    /// # struct Counter(usize);
+    /// # impl Count for Counter { fn count(&self) -> usize { self.0 } }
+    /// # impl_views!(Counter: dyn Count);
+    /// let mut counter = DependentRc::new(Counter(0));
+    /// let view = counter.view::<dyn Count>();
+    ///
+    /// counter.get_mut().unwrap().0 += 1;
+    /// assert_eq!(view.upgrade().unwrap().count(), 1);
+    ///
+    /// let upgraded = view.upgrade().unwrap();
+    /// assert!(counter.get_mut().is_none());
+    /// # drop(upgraded);
+    /// ```
+    ///
+    /// Forgetting the guard rather than dropping it leaves the views borrowed, reporting
+    /// `ViewError::Borrowed`, until the guard of a later call to `get_mut` is dropped:
+    ///
+    /// ```
+    /// # use dependent_view::rc::DependentRc;
+    /// # use dependent_view::{impl_views, ViewError};
+    /// # trait Count { fn count(&self) -> usize; }
+    /// # struct Counter(usize);
+    /// # impl Count for Counter { fn count(&self) -> usize { self.0 } }
+    /// # impl_views!(Counter: dyn Count);
+    /// let mut counter = DependentRc::new(Counter(0));
+    /// let view = counter.view::<dyn Count>();
+    ///
+    /// std::mem::forget(counter.get_mut().unwrap());
+    /// assert_eq!(view.try_upgrade().err(), Some(ViewError::Borrowed));
+    ///
+    /// drop(counter.get_mut());
+    /// assert!(view.upgrade().is_some());
+    /// ```
+    pub fn get_mut(&mut self) -> Option<ItemMut<'_, T>> {
+        // with the views detached, no new upgrade can be made while the struct is borrowed
+        let mut slot = self.shared.slot.borrow_mut();
+        slot.item = None;
+        if Rc::get_mut(&mut self.item).is_none() {
+            slot.item = Some(Rc::downgrade(&self.item));
+            return None;
+        }
+        slot.borrowed = true;
+        drop(slot);
+        Some(ItemMut { item: &mut self.item, shared: &self.shared })
+    }
+
+    /// Produces a view of the underlying struct as a `BoundedView<'a, U>`, for structs that borrow data
+    /// living for `'a`. Unlike `view`, this doesn't require the struct to be `'static`.
+    ///
+    /// Bounded views are not tracked by the `DependentRc`, so they can't be revoked - they are
+    /// invalidated when it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::DependentRc;
+    /// trait Parse { fn remaining(&self) -> &str; }
+    /// struct Parser<'a> { text: &'a str }
+    /// impl<'a> Parse for Parser<'a> { fn remaining(&self) -> &str { self.text } }
+    /// impl_views!(<'a> Parser<'a>: dyn Parse + 'a);
+    ///
+    /// # fn main() {
+    /// let text = String::from("let x = 1;");
+    /// let parser = DependentRc::new(Parser { text: &text });
+    /// let view = parser.bounded_view::<dyn Parse>();
+    /// assert_eq!(view.upgrade().unwrap().remaining(), "let x = 1;");
+    /// # }
+    /// ```
+    ///
+    /// Views can't outlive the data borrowed by the struct:
+    ///
+    /// ```compile_fail
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::DependentRc;
+    /// # trait Parse { fn remaining(&self) -> &str; }
+    /// # struct Parser<'a> { text: &'a str }
+    /// # impl<'a> Parse for Parser<'a> { fn remaining(&self) -> &str { self.text } }
+    /// # impl_views!(<'a> Parser<'a>: dyn Parse + 'a);
+    /// # fn main() {
+    /// let view;
+    /// {
+    ///     let text = String::from("let x = 1;");
+    ///     let parser = DependentRc::new(Parser { text: &text });
+    ///     view = parser.bounded_view::<dyn Parse>(); // error: `text` does not live long enough
+    /// }
+    /// view.upgrade();
+    /// # }
+    /// ```
+    ///
+    /// Nor can such structs be viewed through the `'static` views of `to_view!`:
+    ///
+    /// ```compile_fail
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::DependentRc;
+    /// # trait Parse { fn remaining(&self) -> &str; }
+    /// # struct Parser<'a> { text: &'a str }
+    /// # impl<'a> Parse for Parser<'a> { fn remaining(&self) -> &str { self.text } }
+    /// # fn main() {
+    /// let text = String::from("let x = 1;");
+    /// let parser = DependentRc::new(Parser { text: &text });
+    /// let view = to_view!(parser => dyn Parse); // error: `text` does not live long enough
+    /// # }
+    /// ```
+    pub fn bounded_view<'a, U: ?Sized + 'a>(&self) -> BoundedView<'a, U>
+    where T : ViewAs<U> + 'a
+    {
+        BoundedView { source: Rc::new(Issuer {
+            owner: self.shared.id,
+            shared: Rc::downgrade(&self.shared),
+            upcast: T::upcast_rc,
+            invalidated: self.shared.invalidated(),
+            callbacks: Default::default()
+        }) }
+    }
+
+    /// Replaces the owned struct with `item`, returning the struct it replaced. Views handed out so far
+    /// upgrade to the new struct from then on, while references upgraded beforehand keep the old one
+    /// alive until they are dropped.
+    ///
+    /// `item` may be of a different type, as long as it can be viewed as the owned type: the struct of
+    /// a `DependentRc<dyn Trait>` can be replaced with any type registered as a `dyn Trait` with
+    /// `impl_views!`, after which every view of the trait or its supertraits upgrades to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::DependentRc;
+    /// trait Greet { fn greet(&self) -> String; }
+    /// struct Greeter { greeting: &'static str }
+    /// impl Greet for Greeter { fn greet(&self) -> String { self.greeting.to_string() } }
+    ///
+    /// # fn main() {
+    /// let mut greeter = DependentRc::new(Greeter { greeting: "hello" });
+    /// let view = to_view!(greeter => dyn Greet);
+    /// let in_flight = view.upgrade().unwrap();
+    ///
+    /// greeter.replace(Greeter { greeting: "bonjour" });
+    /// assert_eq!(view.upgrade().unwrap().greet(), "bonjour");
+    /// assert_eq!(in_flight.greet(), "hello");
+    /// # }
+    /// ```
+    ///
+    /// Replacing the struct of a trait object with one of another type:
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::DependentRc;
+    /// trait Entity { fn id(&self) -> usize; }
+    /// trait Player: Entity { fn name(&self) -> &str; }
+    /// struct Hero;
+    /// impl Entity for Hero { fn id(&self) -> usize { 0 } }
+    /// impl Player for Hero { fn name(&self) -> &str { "hero" } }
+    /// struct Villain;
+    /// impl Entity for Villain { fn id(&self) -> usize { 1 } }
+    /// impl Player for Villain { fn name(&self) -> &str { "villain" } }
+    /// impl_views!(Villain: dyn Player);
+    ///
+    /// # fn main() {
+    /// let mut player : DependentRc<dyn Player> = DependentRc::from(Box::new(Hero) as Box<dyn Player>);
+    /// let entity = to_view!(player => dyn Entity);
+    ///
+    /// player.replace(Villain);
+    /// assert_eq!(entity.upgrade().unwrap().id(), 1);
+    /// # }
+    /// ```
+    pub fn replace<V: ViewAs<T>>(&mut self, item: V) -> Rc<T> {
+        self.swap(V::upcast_rc(Rc::new(item)))
+    }
+
+    /// Replaces the owned struct with a boxed one, as `replace`, for structs already boxed as the
+    /// owned type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::DependentRc;
+    /// trait Greet { fn greet(&self) -> String; }
+    /// struct English;
+    /// impl Greet for English { fn greet(&self) -> String { "hello".to_string() } }
+    /// struct French;
+    /// impl Greet for French { fn greet(&self) -> String { "bonjour".to_string() } }
+    ///
+    /// # fn main() {
+    /// let mut greeter : DependentRc<dyn Greet> = DependentRc::from(Box::new(English) as Box<dyn Greet>);
+    /// let view = to_view!(greeter => dyn Greet);
+    ///
+    /// greeter.replace_boxed(Box::new(French));
+    /// assert_eq!(view.upgrade().unwrap().greet(), "bonjour");
+    /// # }
+    /// ```
+    pub fn replace_boxed(&mut self, item: Box<T>) -> Rc<T> {
+        self.swap(Rc::from(item))
+    }
+
+    /// Replaces the owned struct with the result of calling `f` on it, as `replace`.
+    pub fn update<F: FnOnce(&T) -> T>(&mut self, f: F) -> Rc<T>
+    where T : Sized
+    {
+        let item = f(&self.item);
+        self.replace(item)
+    }
+
+    /// Points the views at `item` in place of the current struct, returning the latter
+    fn swap(&mut self, item: Rc<T>) -> Rc<T> {
+        self.shared.slot.borrow_mut().item = Some(Rc::downgrade(&item));
+        mem::replace(&mut self.item, item)
+    }
+}
+
+
+
+impl<T: ?Sized + 'static> DependentRc<T> {
+    /// Produces a view of the underlying struct as a `View<U>`, for any `U` registered with `impl_views!`.
+    ///
+    /// Unlike the macro, this can be used from generic code, by bounding `T: ViewAs<U>`.
+    pub fn view<U: ?Sized + 'static>(&self) -> View<U>
+    where T : ViewAs<U>
+    {
+        self.into_view_internal(T::upcast_rc)
+    }
+
+    /// Suspends every view handed out by this `DependentRc`, until the returned guard is dropped.
+    ///
+    /// While suspended, views can't be upgraded, but unlike revoked views they become upgradable
+    /// again once resumed. Suspensions nest - views resume once every guard has been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::DependentRc;
+    /// trait Save { fn save(&self); }
+    /// struct Level;
+    /// impl Save for Level { fn save(&self) {} }
+    ///
+    /// # fn main() {
+    /// let level = DependentRc::new(Level);
+    /// let view = to_view!(level => dyn Save);
+    ///
+    /// let guard = level.suspend();
+    /// assert!(view.upgrade().is_none());
+    ///
+    /// drop(guard);
+    /// assert!(view.upgrade().is_some());
+    /// # }
+    /// ```
+    pub fn suspend(&self) -> SuspendGuard {
+        self.shared.slot.borrow_mut().suspended += 1;
+        SuspendGuard { shared: Rc::downgrade(&self.shared) as Weak<dyn Suspend> }
+    }
+
+    /// Produces a view of a field of the underlying struct, as selected by `project`. The projection
+    /// can be of the field's own type, or of a trait it implements.
+    ///
+    /// Projections aren't revoked along with views of the type they project to - only by `revoke_all`, or
+    /// individually through the handle returned by `revocable_project` - and are only available for
+    /// sized structs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::rc::DependentRc;
+    /// # use dependent_view::ViewError;
+    /// trait Inventory { fn items(&self) -> usize; }
+    /// struct Bag(usize);
+    /// impl Inventory for Bag { fn items(&self) -> usize { self.0 } }
+    /// struct Stats { health: u32 }
+    /// struct Player { inventory: Bag, stats: Stats }
+    ///
+    /// let player = DependentRc::new(Player { inventory: Bag(3), stats: Stats { health: 10 } });
+    /// let inventory = player.project::<dyn Inventory, _>(|p| &p.inventory);
+    /// let stats = player.project(|p| &p.stats);
+    ///
+    /// assert_eq!(inventory.upgrade().unwrap().items(), 3);
+    /// assert_eq!(stats.upgrade().unwrap().health, 10);
+    ///
+    /// drop(player);
+    /// assert!(inventory.upgrade().is_none());
+    /// assert_eq!(stats.try_upgrade().err(), Some(ViewError::Dropped));
+    /// ```
+    pub fn project<U: ?Sized + 'static, F: Fn(&T) -> &U + 'static>(&self, project: F) -> Projection<U>
+    where T : Sized
+    {
+        self.revocable_project(project).0
+    }
+
+    /// Produces a projection as `project`, along with a `ViewHandle` that revokes just that projection
+    /// and its clones. Every projection has a handle of its own, even if it projects the same field
+    /// as another, through the same function or not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dependent_view::rc::DependentRc;
+    /// struct Stats { health: u32 }
+    /// fn health(stats: &Stats) -> &u32 { &stats.health }
+    ///
+    /// let stats = DependentRc::new(Stats { health: 10 });
+    /// let (bar, bar_handle) = stats.revocable_project(health);
+    /// let (log, _) = stats.revocable_project(health);
+    ///
+    /// bar_handle.revoke();
+    /// assert!(bar.upgrade().is_none());
+    /// assert_eq!(*log.upgrade().unwrap(), 10);
+    /// ```
+    pub fn revocable_project<U: ?Sized + 'static, F: Fn(&T) -> &U + 'static>(&self, project: F) -> (Projection<U>, ViewHandle)
+    where T : Sized
+    {
+        let projector = Rc::new(Projector {
+            shared: Rc::downgrade(&self.shared),
+            project,
+            invalidated: OnceCell::new()
+        });
+        let mut issued = self.shared.issued.borrow_mut();
+        if issued.len() == issued.capacity() {
+            issued.retain(|issued| issued.issuer.strong_count() > 0);
+        }
+        issued.push(Issued { view_type: ViewType::of::<U>(), projection: true, issuer: Rc::downgrade(&projector) as Weak<dyn Issue> });
+        let handle = ViewHandle { issuer: Rc::downgrade(&projector) as Weak<dyn Issue> };
+        (Projection { projector }, handle)
+    }
+
+    /// Produces a view of type `U` along with a `ViewHandle` that revokes just that view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::DependentRc;
+    /// trait Inspect { fn inspect(&self); }
+    /// struct Model;
+    /// impl Inspect for Model { fn inspect(&self) {} }
+    /// impl_views!(Model: dyn Inspect);
+    ///
+    /// # fn main() {
+    /// let model = DependentRc::new(Model);
+    /// let (tool_view, tool_handle) = model.revocable_view::<dyn Inspect>();
+    /// let other_view = model.view::<dyn Inspect>();
+    ///
+    /// // the tool is detached
+    /// tool_handle.revoke();
+    /// assert!(tool_view.upgrade().is_none());
+    /// assert!(other_view.upgrade().is_some());
+    /// # }
+    /// ```
+    pub fn revocable_view<U: ?Sized + 'static>(&self) -> (View<U>, ViewHandle)
+    where T : ViewAs<U>
+    {
+        let issuer : Rc<Issuer<T, U>> = self.issuer(T::upcast_rc);
+        self.track::<U>(&issuer);
+        let handle = ViewHandle { issuer: Rc::downgrade(&issuer) as Weak<dyn Issue> };
+        (View { source: issuer }, handle)
+    }
+
+    /// Produces a view of type `U` issued into `group`, which is revoked when the group is dropped
+    /// or cleared. Repeated views of the same type issued into the same group share one issuer.
+    pub fn view_in<U: ?Sized + 'static>(&self, group: &ViewGroup) -> View<U>
+    where T : ViewAs<U>
+    {
+        let key = (self.shared.id, TypeId::of::<U>());
+        let mut issuers = group.issuers.borrow_mut();
+        if let Some(issuer) = issuers.get(&key).and_then(Weak::upgrade) {
+            if issuer.invalidated().is_none() {
+                let issuer = (issuer as Rc<dyn Any>).downcast::<Issuer<T, U>>()
+                    .expect("group entries are keyed by the TypeId of their view type");
+                return View { source: issuer };
+            }
+        }
+        let issuer : Rc<Issuer<T, U>> = self.issuer(T::upcast_rc);
+        self.track::<U>(&issuer);
+        if issuers.len() == issuers.capacity() {
+            issuers.retain(|_, issuer| issuer.strong_count() > 0);
+        }
+        issuers.insert(key, Rc::downgrade(&issuer) as Weak<dyn Issue>);
+        View { source: issuer }
+    }
+
+    /// Returns a view of type `U`, if one has already been handed out by this `DependentRc` (and not revoked).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::DependentRc;
+    /// # use std::rc::Rc;
+    /// trait Dance { fn dance(&self); }
+    /// trait Prance { fn prance(&self); }
+    /// struct Dancer;
+    /// impl Dance for Dancer { fn dance(&self) {} }
+    /// impl Prance for Dancer { fn prance(&self) {} }
+    ///
+    /// # fn main() {
+    /// let dancer = DependentRc::new(Dancer);
+    /// assert!(dancer.existing_view::<dyn Dance>().is_none());
+    ///
+    /// let view = to_view!(dancer => dyn Dance);
+    /// let again = dancer.existing_view::<dyn Dance>().unwrap();
+    /// assert!(Rc::ptr_eq(&view.upgrade().unwrap(), &again.upgrade().unwrap()));
+    ///
+    /// assert_eq!(dancer.view_types().count(), 1);
+    /// assert!(dancer.view_types().all(|ty| ty.is::<dyn Dance>()));
+    /// # }
+    /// ```
+    pub fn existing_view<U: ?Sized + 'static>(&self) -> Option<View<U>> {
+        let issuer = self.shared.views.borrow().get(&TypeId::of::<U>())?.issuer.clone();
+        let issuer = (issuer as Rc<dyn Any>).downcast::<Issuer<T, U>>()
+            .expect("view entries are keyed by the TypeId of their view type");
+        Some(View { source: issuer })
+    }
+
+    /// Lists the types of view that have been handed out by this `DependentRc`, in no particular order.
+    pub fn view_types(&self) -> impl Iterator<Item = ViewType> {
+        let view_types : Vec<ViewType> = self.shared.views.borrow().values().map(|entry| entry.view_type).collect();
+        view_types.into_iter()
+    }
+
+    /// Revokes every view of type `U` handed out so far, including those issued with a `ViewHandle`.
+    /// The `DependentRc` keeps working, and views handed out afterwards can be upgraded as normal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate dependent_view;
+    /// # use dependent_view::rc::DependentRc;
+    /// trait Inspect { fn inspect(&self); }
+    /// trait Render { fn render(&self); }
+    /// struct Model;
+    /// impl Inspect for Model { fn inspect(&self) {} }
+    /// impl Render for Model { fn render(&self) {} }
+    ///
+    /// # fn main() {
+    /// let model = DependentRc::new(Model);
+    /// let inspector = to_view!(model => dyn Inspect);
+    /// let renderer = to_view!(model => dyn Render);
+    ///
+    /// model.revoke::<dyn Inspect>();
+    /// assert!(inspector.upgrade().is_none());
+    /// assert!(renderer.upgrade().is_some());
+    ///
+    /// let inspector = to_view!(model => dyn Inspect);
+    /// assert!(inspector.upgrade().is_some());
+    /// # }
+    /// ```
+    pub fn revoke<U: ?Sized + 'static>(&self) {
+        let mut issuers : Vec<Rc<dyn Issue>> = Vec::new();
+        issuers.extend(self.shared.views.borrow_mut().remove(&TypeId::of::<U>()).map(|entry| entry.issuer));
+        self.shared.issued.borrow_mut().retain(|issued| {
+            if issued.projection || !issued.view_type.is::<U>() {
+                return true;
+            }
+            issuers.extend(issued.issuer.upgrade());
+            false
+        });
+        invalidate(issuers, ViewError::Revoked);
+    }
+
+    /// Tracks an issuer of views handed out outside of the per-type entries, so it can be revoked
+    fn track<U: ?Sized + 'static>(&self, issuer: &Rc<Issuer<T, U>>) {
+        let mut issued = self.shared.issued.borrow_mut();
+        if issued.len() == issued.capacity() {
+            issued.retain(|issued| issued.issuer.strong_count() > 0);
         }
+        issued.push(Issued { view_type: ViewType::of::<U>(), projection: false, issuer: Rc::downgrade(issuer) as Weak<dyn Issue> });
     }
 
-    /// internal hidden function used to produce a Weak reference
+    /// Constructs a new issuer of views of type `U`
+    fn issuer<U: ?Sized + 'static>(&self, upcast: fn(Rc<T>) -> Rc<U>) -> Rc<Issuer<T, U>> {
+        Rc::new(Issuer {
+            owner: self.shared.id,
+            shared: Rc::downgrade(&self.shared),
+            upcast,
+            invalidated: self.shared.invalidated(),
+            callbacks: Default::default()
+        })
+    }
+
+    /// internal hidden function used to produce a View
     /// # Warn
     /// This function should only be called through the `to_view!` macro. It is not intended for direct use.
     /// # Remarks
-    /// This struct works by cloning the underlying Rc, using the fact that when cloning, it is possible
-    /// to upcast an `Rc<Concrete>` to an `Rc<Trait>`.
-    /// From this point, the `Rc<Trait>` is transmuted into an `Rc<Any>`. While this is an unsafe,
-    /// unchecked cast, we just want to keep the on Drop functionality of `Rc<Any>` and don't provide    /// any ways to use the trait, so it shouldn't be a problem
+    /// This struct works by using the fact that it is possible to upcast an `Rc<Concrete>` to an
+    /// `Rc<Trait>` - the `conversion` passed in by the macro is just such an upcast, so the compiler
+    /// checks that `T` implements the trait. The upcast is stored in an issuer along with a `Weak`
+    /// reference to `item`, through which views of that type are upgraded.
+    /// The issuer for each view type is created once, and shared by all later views of that type.
+    #[doc(hidden)]
+    pub fn into_view_internal<U: ?Sized + 'static>(&self, conversion: fn(Rc<T>) -> Rc<U>) -> View<U> {
+        issue(&self.shared, conversion)
+    }
+
     #[doc(hidden)]
-    pub unsafe fn into_view_internal<X,Y,G,F, H>(&mut self, conversion: F, downgrade: G, to_any: H) -> Y
-    where F : FnOnce(&Rc<T>) -> X,
-          G : FnOnce(&X) -> Y,
-          H : FnOnce(X) -> Rc<Any>
+    pub fn into_mut_view_internal<U: ?Sized + 'static>(&self, conversion: fn(Rc<T>) -> Rc<T::With<U>>) -> View<T::With<U>>
+    where T : InteriorMut, T::With<U> : 'static
     {
-        let reference : X = conversion(&self.item);
-        let reference : Rc<Any> = to_any(reference);
-        let reference : &Rc<Any> = push_ref(&mut self.dependants, reference);
-        let reference : &X =  transmute(reference);
-        downgrade(reference)
+        self.into_view_internal(conversion)
     }
 }
 
 
 
-impl<T> Deref for DependentRc<T> {
+impl<T: ?Sized> Deref for DependentRc<T> {
     type Target = Rc<T>;
 
     fn deref(&self) -> &Rc<T> {
         &self.item
     }
 }
-impl<T> DerefMut for DependentRc<T> {
-    fn deref_mut(&mut self) -> &mut Rc<T> {
-        &mut self.item
-    }
-}
 
 
 /// Returns a reference to the underlying `Rc` struct
-impl<T> AsRef<Rc<T>> for DependentRc<T> {
+impl<T: ?Sized> AsRef<Rc<T>> for DependentRc<T> {
     fn as_ref(&self) -> &Rc<T> {
         &self.item
     }
 }
 
-/// Returns a mutable reference to the underlying `Rc` struct
-impl<T> AsMut<Rc<T>> for DependentRc<T> {
-    fn as_mut(&mut self) -> &mut Rc<T> {
-        &mut self.item
+
+/// Constructs a DependentRc from an `Rc`, imbuing it with the capability to produce views.
+impl<T: ?Sized> From<Rc<T>> for DependentRc<T> {
+    fn from(item: Rc<T>) -> DependentRc<T> {
+        DependentRc {
+            shared: Rc::new(Shared::new(next_owner_id(), Some(Rc::downgrade(&item)))),
+            item
+        }
     }
 }
 
+/// Constructs a DependentRc from a `Box`, which allows unsized structs such as trait objects to be owned.
+///
+/// Views can be produced of any supertrait of an owned trait object.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate dependent_view;
+/// # use dependent_view::rc::DependentRc;
+/// trait Entity { fn id(&self) -> usize; }
+/// trait Player: Entity { fn name(&self) -> &str; }
+/// struct Hero;
+/// impl Entity for Hero { fn id(&self) -> usize { 0 } }
+/// impl Player for Hero { fn name(&self) -> &str { "hero" } }
+///
+/// fn spawn() -> Box<dyn Player> { Box::new(Hero) }
+///
+/// # fn main() {
+/// let player : DependentRc<dyn Player> = DependentRc::from(spawn());
+/// let entity = to_view!(player => dyn Entity);
+/// let same = to_view!(player => dyn Player);
+/// assert_eq!(entity.upgrade().unwrap().id(), 0);
+/// assert_eq!(same.upgrade().unwrap().name(), "hero");
+/// # }
+/// ```
+impl<T: ?Sized> From<Box<T>> for DependentRc<T> {
+    fn from(item: Box<T>) -> DependentRc<T> {
+        DependentRc::from(Rc::from(item))
+    }
+}
 
-/// Constructs a DependentRc from an `Rc`, imbuing it with the capability to produce views.
-impl <T> From<Rc<T>> for DependentRc<T> {
-    fn from(item: Rc<T>) -> DependentRc<T> {
+/// Unwraps the `DependentRc`, returning it's internal `Rc`
+///
+/// Note: This will invalidate all `View<Trait>` views you have constructed from this object.
+impl<T: ?Sized> From<DependentRc<T>> for Rc<T> {
+    fn from(dependent: DependentRc<T>) -> Rc<T> {
+        Rc::clone(&dependent.item)
+    }
+}
+
+impl<T: ?Sized> Drop for DependentRc<T> {
+    fn drop(&mut self) {
+        self.shared.end(ViewError::Dropped);
+    }
+}
+
+
+
+/// `LazyRc<T>` is a `DependentRc<T>` to be, produced by `DependentRc::lazy`. It hands out views
+/// straight away, which can't be upgraded until the struct is provided with `set`.
+///
+/// Dropping a `LazyRc` without setting it kills its views for good.
+///
+/// # Examples
+///
+/// ```
+/// # use dependent_view::rc::{DependentRc, View};
+/// # use dependent_view::impl_views;
+/// trait Service { fn call(&self) -> usize; }
+/// struct Database { cache: View<dyn Service> }
+/// struct Cache { database: View<dyn Service> }
+/// impl Service for Database { fn call(&self) -> usize { 1 } }
+/// impl Service for Cache { fn call(&self) -> usize { self.database.upgrade().map_or(0, |db| db.call()) + 1 } }
+/// impl_views!(Database: dyn Service);
+/// impl_views!(Cache: dyn Service);
+///
+/// // the database and cache are wired to each other
+/// let cache = DependentRc::<Cache>::lazy();
+/// let database = DependentRc::new(Database { cache: cache.view::<dyn Service>() });
+/// assert!(database.cache.upgrade().is_none());
+///
+/// let cache = cache.set(Cache { database: database.view::<dyn Service>() });
+/// assert_eq!(database.cache.upgrade().unwrap().call(), 2);
+/// # drop(cache);
+/// ```
+pub struct LazyRc<T> {
+    shared: Rc<Shared<T>>
+}
+
+impl<T: 'static> LazyRc<T> {
+    /// Produces a view of the struct to be as a `View<U>`, for any `U` registered with `impl_views!`.
+    /// The view can be upgraded once the struct has been set.
+    pub fn view<U: ?Sized + 'static>(&self) -> View<U>
+    where T : ViewAs<U>
+    {
+        issue(&self.shared, T::upcast_rc)
+    }
+
+    /// Returns a `Views` handle, through which views of the struct to be can be produced without the
+    /// `LazyRc`. Views produced after it has been dropped without being set report `ViewError::Expired`.
+    ///
+    /// ```
+    /// # use dependent_view::{impl_views, ViewError};
+    /// # use dependent_view::rc::DependentRc;
+    /// trait Render { fn render(&self); }
+    /// struct Canvas;
+    /// impl Render for Canvas { fn render(&self) {} }
+    /// impl_views!(Canvas: dyn Render);
+    ///
+    /// let canvas = DependentRc::<Canvas>::lazy();
+    /// let views = canvas.views();
+    /// drop(canvas);
+    /// assert_eq!(views.view::<dyn Render>().try_upgrade().err(), Some(ViewError::Expired));
+    /// ```
+    pub fn views(&self) -> Views<T> {
+        Shared::views(&self.shared)
+    }
+}
+
+impl<T> LazyRc<T> {
+    /// Provides the struct, turning this into a `DependentRc`. Views handed out so far can be upgraded
+    /// from then on.
+    pub fn set(self, item: T) -> DependentRc<T> {
+        let item = Rc::new(item);
+        self.shared.slot.borrow_mut().item = Some(Rc::downgrade(&item));
         DependentRc {
             item,
-            dependants: Vec::new()
+            shared: self.shared.clone()
         }
     }
 }
 
-/// Unwraps the `DependentRc`, returning it's internal `Rc`
+impl<T> Drop for LazyRc<T> {
+    fn drop(&mut self) {
+        if self.shared.slot.borrow().item.is_none() {
+            self.shared.end(ViewError::Expired);
+        }
+    }
+}
+
+
+
+/// `StrictRc<T>` is a variant of `DependentRc<T>` that is the single owner of its struct in
+/// practice, not just by convention - dropping it always drops the struct.
 ///
-/// Note: This will invalidate all `Weak<Trait>` views you have constructed from this object.
-impl <T> Into<Rc<T>> for DependentRc<T> {
-    fn into(self) -> Rc<T> {
-        self.item
+/// Its views, `StrictView<U>`, can only be borrowed for a short time through a `ViewRef` guard,
+/// which can't be cloned or outlive the view it was borrowed from, and the `StrictRc` only derefs
+/// to the struct itself, so no `Rc` to the struct ever escapes.
+///
+/// # Panics
+/// Dropping a `StrictRc` while a `ViewRef` to it is alive panics, as the struct could not be dropped.
+///
+/// ```should_panic
+/// # use dependent_view::rc::StrictRc;
+/// # use dependent_view::impl_views;
+/// # trait Listen { fn listen(&self); }
+/// # struct Listener;
+/// # impl Listen for Listener { fn listen(&self) {} }
+/// # impl_views!(Listener: dyn Listen);
+/// let listener = StrictRc::new(Listener);
+/// let view = listener.view::<dyn Listen>();
+/// let guard = view.borrow().unwrap();
+/// drop(listener);
+/// # drop(guard);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// # use dependent_view::rc::StrictRc;
+/// # use dependent_view::{impl_views, ViewError};
+/// # use std::rc::Rc;
+/// # use std::cell::Cell;
+/// trait Listen { fn listen(&self); }
+/// struct Listener { dropped: Rc<Cell<bool>> }
+/// impl Listen for Listener { fn listen(&self) {} }
+/// impl Drop for Listener { fn drop(&mut self) { self.dropped.set(true); } }
+/// impl_views!(Listener: dyn Listen);
+///
+/// let dropped = Rc::new(Cell::new(false));
+/// let listener = StrictRc::new(Listener { dropped: dropped.clone() });
+/// let view = listener.view::<dyn Listen>();
+///
+/// if let Some(guard) = view.borrow() {
+///     guard.listen();
+/// }
+///
+/// drop(listener);
+/// assert!(dropped.get());
+/// assert_eq!(view.try_borrow().err(), Some(ViewError::Dropped));
+/// ```
+pub struct StrictRc<T> {
+    inner: DependentRc<T>
+}
+
+impl<T> StrictRc<T> {
+    /// Constructs a `StrictRc` by wrapping an underlying type
+    pub fn new(item: T) -> StrictRc<T> {
+        StrictRc { inner: DependentRc::new(item) }
+    }
+
+    /// Revokes every view handed out so far, as `DependentRc::revoke_all`.
+    pub fn revoke_all(&self) {
+        self.inner.revoke_all()
+    }
+}
+
+impl<T: 'static> StrictRc<T> {
+    /// Produces a view of the underlying struct as a `StrictView<U>`, for any `U` registered with `impl_views!`.
+    pub fn view<U: ?Sized + 'static>(&self) -> StrictView<U>
+    where T : ViewAs<U>
+    {
+        StrictView { view: self.inner.view() }
+    }
+
+    /// Produces a view of type `U` issued into `group`, as `DependentRc::view_in`.
+    pub fn view_in<U: ?Sized + 'static>(&self, group: &ViewGroup) -> StrictView<U>
+    where T : ViewAs<U>
+    {
+        StrictView { view: self.inner.view_in(group) }
+    }
+
+    /// Revokes every view of type `U` handed out so far, as `DependentRc::revoke`.
+    pub fn revoke<U: ?Sized + 'static>(&self) {
+        self.inner.revoke::<U>()
+    }
+
+    /// Suspends every view handed out so far, as `DependentRc::suspend`.
+    pub fn suspend(&self) -> SuspendGuard {
+        self.inner.suspend()
+    }
+}
+
+impl<T> Deref for StrictRc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner.item
+    }
+}
+
+impl<T> Drop for StrictRc<T> {
+    fn drop(&mut self) {
+        if Rc::strong_count(&self.inner.item) > 1 && !thread::panicking() {
+            panic!("StrictRc dropped while a view of it was borrowed");
+        }
+    }
+}
+
+
+/// A view of a struct owned by a `StrictRc`, which can only be borrowed temporarily.
+///
+/// A borrow can't outlive the view it was taken from:
+///
+/// ```compile_fail
+/// # use dependent_view::rc::StrictRc;
+/// # use dependent_view::impl_views;
+/// trait Listen { fn listen(&self); }
+/// struct Listener;
+/// impl Listen for Listener { fn listen(&self) {} }
+/// impl_views!(Listener: dyn Listen);
+///
+/// let listener = StrictRc::new(Listener);
+/// let guard = {
+///     let view = listener.view::<dyn Listen>();
+///     view.borrow().unwrap()
+/// };
+/// guard.listen();
+/// ```
+pub struct StrictView<U: ?Sized> {
+    view: View<U>
+}
+
+impl<U: ?Sized> StrictView<U> {
+    /// Borrows the viewed struct, returning `None` if the owning `StrictRc` has been dropped or
+    /// suspended, or the view has been revoked.
+    pub fn borrow(&self) -> Option<ViewRef<'_, U>> {
+        self.try_borrow().ok()
+    }
+
+    /// Borrows the viewed struct, returning the reason it is unavailable if it can't be borrowed.
+    pub fn try_borrow(&self) -> Result<ViewRef<'_, U>, ViewError> {
+        self.view.try_upgrade().map(|value| ViewRef { value, view: PhantomData })
+    }
+
+    /// Calls `f` with the viewed struct, if it can be borrowed.
+    pub fn with<R, F: FnOnce(&U) -> R>(&self, f: F) -> Option<R> {
+        self.borrow().map(|value| f(&value))
+    }
+}
+
+impl<U: ?Sized> Clone for StrictView<U> {
+    fn clone(&self) -> StrictView<U> {
+        StrictView { view: self.view.clone() }
+    }
+}
+
+
+/// A temporary borrow of a struct owned by a `StrictRc`, produced by `StrictView::borrow`.
+pub struct ViewRef<'a, U: ?Sized> {
+    value: Rc<U>,
+    view: PhantomData<&'a StrictView<U>>
+}
+
+impl<U: ?Sized> Deref for ViewRef<'_, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        &self.value
     }
 }
@@ -0,0 +1,185 @@
+//! Module defining scopes, which own `DependentRc`s and `DependentArc`s until the end of a closure.
+//!
+//! Every owner created through a `Scope` lives until the scope ends, and is dropped by then - so
+//! every view handed out by it is guaranteed to be dead once `scope` returns. Owners are handed out
+//! as `Owned` and `SyncOwned` handles, which can't escape the scope, no matter which collections
+//! they are pushed into. Should a handle be leaked anyway, its owner's views are still invalidated
+//! as the scope ends.
+//!
+//! # Examples
+//!
+//! ```
+//! # use std::thread;
+//! trait Handle { fn handle(&self) -> usize; }
+//! struct Request { id: usize }
+//! impl Handle for Request { fn handle(&self) -> usize { self.id } }
+//! dependent_view::impl_views!(Request: dyn Handle);
+//!
+//! let mut handled = Vec::new();
+//! let view = dependent_view::scope(|s| {
+//!     let request = s.own_sync(Request { id: 7 });
+//!     let view = request.view::<dyn Handle>();
+//!
+//!     // views handed to scoped threads are valid for as long as the scope
+//!     thread::scope(|threads| {
+//!         threads.spawn(|| handled.push(view.upgrade().unwrap().handle()));
+//!     });
+//!     view
+//! });
+//!
+//! assert_eq!(handled, vec![7]);
+//! assert!(view.upgrade().is_none());
+//! ```
+//!
+//! Handles to owners can't outlive the scope:
+//!
+//! ```compile_fail
+//! # struct Request { id: usize }
+//! let mut requests = Vec::new();
+//! dependent_view::scope(|s| {
+//!     requests.push(s.own(Request { id: 7 })); // error: borrowed data escapes the closure
+//! });
+//! ```
+
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+use super::arc::DependentArc;
+use super::rc::DependentRc;
+
+
+/// Creates a scope owning `DependentRc`s and `DependentArc`s, which are all dropped before this
+/// function returns.
+///
+/// Modeled after `std::thread::scope`: `f` is passed a `Scope`, through which owners can be created
+/// that live until the end of the scope, and the result of `f` is returned. Owners are dropped in
+/// the reverse order they were created in, even if `f` panics.
+pub fn scope<'env, F, R>(f: F) -> R
+where F : for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> R
+{
+    let scope = Scope {
+        owners: Owners(RefCell::new(Vec::new())),
+        scope: PhantomData,
+        env: PhantomData
+    };
+    f(&scope)
+}
+
+
+/// A scope to create owners in, as passed to the closure of `scope`.
+pub struct Scope<'scope, 'env: 'scope> {
+    owners: Owners<'env>,
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>
+}
+
+/// The owners kept alive by a scope, ended and dropped in the reverse order they were created in
+struct Owners<'env>(RefCell<Vec<Box<dyn End + 'env>>>);
+
+/// Type erased owner kept alive by a scope
+trait End {
+    /// Invalidates the views of the owner, whether or not a handle to it is still around
+    fn end(&self);
+}
+
+impl<T: ?Sized> End for Rc<DependentRc<T>> {
+    fn end(&self) {
+        DependentRc::end(self);
+    }
+}
+
+impl<T: ?Sized> End for Arc<DependentArc<T>> {
+    fn end(&self) {
+        DependentArc::end(self);
+    }
+}
+
+impl<'scope, 'env> Scope<'scope, 'env> {
+    /// Wraps `item` in a `DependentRc` owned by the scope.
+    pub fn own<T: 'env>(&'scope self, item: T) -> Owned<'scope, DependentRc<T>> {
+        let owner = Rc::new(DependentRc::new(item));
+        self.owners.0.borrow_mut().push(Box::new(owner.clone()));
+        Owned { owner, scope: PhantomData }
+    }
+
+    /// Wraps `item` in a `DependentArc` owned by the scope.
+    pub fn own_sync<T: Send + Sync + 'env>(&'scope self, item: T) -> SyncOwned<'scope, DependentArc<T>> {
+        let owner = Arc::new(DependentArc::new(item));
+        self.owners.0.borrow_mut().push(Box::new(owner.clone()));
+        SyncOwned { owner, scope: PhantomData }
+    }
+}
+
+impl Drop for Owners<'_> {
+    fn drop(&mut self) {
+        let owners = self.0.get_mut();
+        while let Some(owner) = owners.pop() {
+            owner.end();
+            drop(owner);
+        }
+    }
+}
+
+
+/// A handle to a `DependentRc` created by `Scope::own`, which dereferences to the owner. The owner
+/// lives until the end of the scope, even if every handle to it has been dropped.
+///
+/// Its views are invalidated as the scope ends, even if a handle has been leaked and keeps the
+/// owner itself alive:
+///
+/// ```
+/// # use dependent_view::ViewError;
+/// trait Handle { fn handle(&self) -> usize; }
+/// struct Request { id: usize }
+/// impl Handle for Request { fn handle(&self) -> usize { self.id } }
+/// dependent_view::impl_views!(Request: dyn Handle);
+///
+/// let view = dependent_view::scope(|s| {
+///     let request = s.own(Request { id: 7 });
+///     std::mem::forget(request.clone());
+///     request.view::<dyn Handle>()
+/// });
+/// assert_eq!(view.try_upgrade().err(), Some(ViewError::Dropped));
+/// ```
+pub struct Owned<'scope, O> {
+    owner: Rc<O>,
+    scope: PhantomData<&'scope mut &'scope ()>
+}
+
+impl<O> Deref for Owned<'_, O> {
+    type Target = O;
+
+    fn deref(&self) -> &O {
+        &self.owner
+    }
+}
+
+impl<O> Clone for Owned<'_, O> {
+    fn clone(&self) -> Self {
+        Owned { owner: self.owner.clone(), scope: PhantomData }
+    }
+}
+
+
+/// A handle to a `DependentArc` created by `Scope::own_sync`, as `Owned`. Unlike `Owned`, it can be
+/// shared with scoped threads.
+pub struct SyncOwned<'scope, O> {
+    owner: Arc<O>,
+    scope: PhantomData<&'scope mut &'scope ()>
+}
+
+impl<O> Deref for SyncOwned<'_, O> {
+    type Target = O;
+
+    fn deref(&self) -> &O {
+        &self.owner
+    }
+}
+
+impl<O> Clone for SyncOwned<'_, O> {
+    fn clone(&self) -> Self {
+        SyncOwned { owner: self.owner.clone(), scope: PhantomData }
+    }
+}
@@ -0,0 +1,100 @@
+#[macro_use]
+extern crate dependent_view;
+
+use dependent_view::arc::DependentArc;
+use dependent_view::rc::DependentRc;
+use dependent_view::ViewError;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+trait Name {
+    fn name(&self) -> &'static str;
+}
+
+trait Age {
+    fn age(&self) -> u32;
+}
+
+struct Cat {
+    dropped: Arc<AtomicBool>
+}
+
+impl Name for Cat {
+    fn name(&self) -> &'static str {
+        "cat"
+    }
+}
+
+impl Age for Cat {
+    fn age(&self) -> u32 {
+        3
+    }
+}
+
+impl Drop for Cat {
+    fn drop(&mut self) {
+        self.dropped.store(true, Ordering::SeqCst);
+    }
+}
+
+impl_views!(Cat: dyn Name, dyn Age);
+
+#[test]
+fn callbacks_cannot_upgrade_sibling_views_while_the_owner_drops() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let owner = DependentRc::new(Cat { dropped: dropped.clone() });
+    let name = owner.view::<dyn Name>();
+    let age = owner.view::<dyn Age>();
+    let (revocable, _handle) = owner.revocable_view::<dyn Name>();
+    let stashed : Rc<RefCell<Vec<Rc<dyn Name>>>> = Rc::default();
+    let reasons = Rc::new(RefCell::new(Vec::new()));
+
+    // whichever view is invalidated first, none of the others can be upgraded from its callback
+    for view in [name.clone(), revocable.clone()] {
+        let (name, revocable, age) = (name.clone(), revocable.clone(), age.clone());
+        let (stashed, reasons) = (stashed.clone(), reasons.clone());
+        view.on_invalidate(move |reason| {
+            stashed.borrow_mut().extend(name.upgrade().into_iter().chain(revocable.upgrade()));
+            assert!(age.upgrade().is_none());
+            reasons.borrow_mut().push(reason);
+        });
+    }
+    let (name, stashed_age) = (name.clone(), Rc::new(RefCell::new(None)));
+    let stash = stashed_age.clone();
+    age.on_invalidate(move |_| *stash.borrow_mut() = name.upgrade().map(|name| name.name()));
+
+    drop(owner);
+    assert!(stashed.borrow().is_empty());
+    assert!(stashed_age.borrow().is_none());
+    assert_eq!(*reasons.borrow(), [ViewError::Dropped, ViewError::Dropped]);
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[test]
+fn callbacks_cannot_upgrade_sibling_sync_views_while_the_owner_drops() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let owner = DependentArc::new(Cat { dropped: dropped.clone() });
+    let name = owner.view::<dyn Name>();
+    let age = owner.view::<dyn Age>();
+    let (revocable, _handle) = owner.revocable_view::<dyn Age>();
+    let upgraded : Arc<Mutex<Vec<u32>>> = Arc::default();
+
+    for view in [age.clone(), revocable.clone()] {
+        let (age, revocable, name) = (age.clone(), revocable.clone(), name.clone());
+        let upgraded = upgraded.clone();
+        view.on_invalidate(move |_| {
+            upgraded.lock().unwrap().extend(age.upgrade().into_iter().chain(revocable.upgrade()).map(|age| age.age()));
+            assert!(name.upgrade().is_none());
+        });
+    }
+    let age = age.clone();
+    let record = upgraded.clone();
+    name.on_invalidate(move |_| record.lock().unwrap().extend(age.upgrade().map(|age| age.age())));
+
+    drop(owner);
+    assert!(upgraded.lock().unwrap().is_empty());
+    assert!(dropped.load(Ordering::SeqCst));
+    assert_eq!(name.try_upgrade().err(), Some(ViewError::Dropped));
+}
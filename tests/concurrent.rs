@@ -0,0 +1,356 @@
+#[macro_use]
+extern crate dependent_view;
+
+use dependent_view::arc::{DependentArc, SyncView};
+use dependent_view::ViewError;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::Duration;
+
+trait Probe {
+    fn alive(&self) -> bool;
+}
+
+struct Dancer {
+    dropped: Arc<AtomicBool>,
+    drops: Arc<AtomicUsize>
+}
+
+impl Probe for Dancer {
+    fn alive(&self) -> bool {
+        !self.dropped.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for Dancer {
+    fn drop(&mut self) {
+        self.dropped.store(true, Ordering::SeqCst);
+        self.drops.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl_views!(Dancer: dyn Probe);
+
+const THREADS: usize = 8;
+const ROUNDS: usize = 200;
+
+struct Unpark(Thread);
+
+impl Wake for Unpark {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&waker)) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+#[test]
+fn concurrent_view_creation_races_owner_drop() {
+    for _ in 0..ROUNDS {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let drops = Arc::new(AtomicUsize::new(0));
+        let owner = Arc::new(DependentArc::new(Dancer { dropped: dropped.clone(), drops: drops.clone() }));
+        let barrier = Arc::new(Barrier::new(THREADS + 1));
+
+        let workers : Vec<_> = (0..THREADS).map(|_| {
+            let owner = owner.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                let mut views : Vec<SyncView<dyn Probe>> = Vec::new();
+                barrier.wait();
+                // create views and upgrade them while the owner may be dropped by any thread
+                for _ in 0..16 {
+                    views.push(to_view_sync!(owner => dyn Probe));
+                    for view in &views {
+                        if let Some(probe) = view.upgrade() {
+                            assert!(probe.alive());
+                        }
+                    }
+                }
+                // whichever thread lets go of the owner last drops it
+                drop(owner);
+                views
+            })
+        }).collect();
+
+        barrier.wait();
+        drop(owner);
+
+        let views : Vec<SyncView<dyn Probe>> = workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect();
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+        assert!(views.iter().all(|view| view.upgrade().is_none()));
+    }
+}
+
+#[test]
+fn views_created_from_a_shared_owner_are_revoked_together() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let drops = Arc::new(AtomicUsize::new(0));
+    let owner = DependentArc::new(Dancer { dropped, drops });
+
+    let views : Vec<SyncView<dyn Probe>> = thread::scope(|s| {
+        let workers : Vec<_> = (0..THREADS).map(|_| s.spawn(|| {
+            (0..64).map(|_| owner.view::<dyn Probe>()).collect::<Vec<_>>()
+        })).collect();
+        workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect()
+    });
+
+    assert!(views.iter().all(|view| view.upgrade().is_some()));
+    owner.revoke::<dyn Probe>();
+    assert!(views.iter().all(|view| view.upgrade().is_none()));
+}
+
+#[test]
+fn replacing_races_upgrades() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let dancer = |drops: &Arc<AtomicUsize>| Dancer { dropped: Arc::new(AtomicBool::new(false)), drops: drops.clone() };
+    let owner = DependentArc::new(dancer(&drops));
+    let view = owner.view::<dyn Probe>();
+    let done = Arc::new(AtomicBool::new(false));
+
+    let readers : Vec<_> = (0..THREADS).map(|_| {
+        let view = view.clone();
+        let done = done.clone();
+        thread::spawn(move || {
+            while !done.load(Ordering::SeqCst) {
+                // every upgrade sees a whole value, whether it is the old or the new one
+                assert!(view.upgrade().unwrap().alive());
+            }
+        })
+    }).collect();
+
+    for _ in 0..ROUNDS {
+        drop(owner.replace(dancer(&drops)));
+    }
+    done.store(true, Ordering::SeqCst);
+    for reader in readers {
+        reader.join().unwrap();
+    }
+
+    assert_eq!(drops.load(Ordering::SeqCst), ROUNDS);
+    drop(owner);
+    assert_eq!(drops.load(Ordering::SeqCst), ROUNDS + 1);
+    assert!(view.upgrade().is_none());
+}
+
+#[test]
+fn callbacks_registered_while_the_owner_drops_run_exactly_once() {
+    for _ in 0..ROUNDS {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let drops = Arc::new(AtomicUsize::new(0));
+        let owner = DependentArc::new(Dancer { dropped, drops });
+        let view = owner.view::<dyn Probe>();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(THREADS + 1));
+
+        let workers : Vec<_> = (0..THREADS).map(|_| {
+            let view = view.clone();
+            let runs = runs.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                let mut registered = 0;
+                for round in 0..16 {
+                    let runs = runs.clone();
+                    let other = view.clone();
+                    let handle = view.on_invalidate(move |_| {
+                        // callbacks may upgrade views of the owner being dropped
+                        assert!(other.upgrade().is_none());
+                        runs.fetch_add(1, Ordering::SeqCst);
+                    });
+                    if round % 4 == 0 && handle.cancel() {
+                        continue;
+                    }
+                    registered += 1;
+                }
+                registered
+            })
+        }).collect();
+
+        barrier.wait();
+        drop(owner);
+
+        let registered : usize = workers.into_iter().map(|worker| worker.join().unwrap()).sum();
+        assert_eq!(runs.load(Ordering::SeqCst), registered);
+    }
+}
+
+#[test]
+fn drop_and_wait_drops_the_struct_once_upgrades_are_released() {
+    for _ in 0..ROUNDS / 10 {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let drops = Arc::new(AtomicUsize::new(0));
+        let owner = DependentArc::new(Dancer { dropped: dropped.clone(), drops: drops.clone() });
+        let view = owner.view::<dyn Probe>();
+        let barrier = Arc::new(Barrier::new(THREADS + 1));
+
+        let readers : Vec<_> = (0..THREADS).map(|_| {
+            let view = view.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                // upgrade until the owner goes away, holding each reference for a while
+                while let Some(probe) = view.upgrade() {
+                    assert!(probe.alive());
+                    thread::yield_now();
+                }
+            })
+        }).collect();
+
+        barrier.wait();
+        assert!(owner.drop_and_wait(Duration::from_secs(60)).is_ok());
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+        assert!(view.upgrade().is_none());
+        for reader in readers {
+            reader.join().unwrap();
+        }
+    }
+}
+
+#[test]
+fn drop_and_wait_timing_out_leaves_the_views_working() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let owner = DependentArc::new(Dancer { dropped: Arc::default(), drops: drops.clone() });
+    let view = owner.view::<dyn Probe>();
+    let invalidated = Arc::new(AtomicBool::new(false));
+    let flag = invalidated.clone();
+    view.on_invalidate(move |_| flag.store(true, Ordering::SeqCst));
+
+    let held = view.upgrade().unwrap();
+    let owner = owner.drop_and_wait(Duration::from_millis(10)).unwrap_err();
+    assert!(view.is_alive());
+    assert!(view.upgrade().is_some());
+    assert!(!invalidated.load(Ordering::SeqCst));
+
+    drop(held);
+    assert!(owner.drop_and_wait(Duration::from_secs(60)).is_ok());
+    assert!(invalidated.load(Ordering::SeqCst));
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn drop_async_resolves_once_references_are_released() {
+    for _ in 0..ROUNDS / 10 {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let owners : Vec<_> = (0..THREADS).map(|_| DependentArc::new(Dancer { dropped: Arc::default(), drops: drops.clone() })).collect();
+        let barrier = Arc::new(Barrier::new(THREADS + 1));
+
+        let readers : Vec<_> = owners.iter().enumerate().map(|(index, owner)| {
+            let view : SyncView<dyn Probe> = owner.view();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                // half of the readers release their references through `with`, which wakes the owner
+                loop {
+                    let alive = match index % 2 {
+                        0 => view.with(|probe| assert!(probe.alive())).is_some(),
+                        _ => view.upgrade().map(|probe| assert!(probe.alive())).is_some()
+                    };
+                    if !alive {
+                        break;
+                    }
+                    thread::yield_now();
+                }
+            })
+        }).collect();
+
+        barrier.wait();
+        for owner in owners {
+            block_on(owner.drop_async());
+        }
+        assert_eq!(drops.load(Ordering::SeqCst), THREADS);
+        for reader in readers {
+            reader.join().unwrap();
+        }
+    }
+}
+
+#[test]
+fn dropping_a_pending_drop_async_drops_the_owner() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let owner = DependentArc::new(Dancer { dropped: dropped.clone(), drops: Arc::default() });
+    let view = owner.view::<dyn Probe>();
+    let invalidated = Arc::new(Mutex::new(None));
+    let reason = invalidated.clone();
+    view.on_invalidate(move |error| *reason.lock().unwrap() = Some(error));
+    let held = view.upgrade().unwrap();
+
+    // suspended while the future waits, as with `drop_and_wait`
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut future = Box::pin(owner.drop_async());
+    assert!(future.as_mut().poll(&mut Context::from_waker(&waker)).is_pending());
+    assert_eq!(view.try_upgrade().err(), Some(ViewError::Suspended));
+    assert!(invalidated.lock().unwrap().is_none());
+
+    drop(future);
+    assert_eq!(view.try_upgrade().err(), Some(ViewError::Dropped));
+    assert_eq!(*invalidated.lock().unwrap(), Some(ViewError::Dropped));
+    assert!(held.alive());
+    drop(held);
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[test]
+fn drop_async_invalidates_the_views_like_drop_and_wait() {
+    for drop_async in [false, true] {
+        let owner = DependentArc::new(Dancer { dropped: Arc::default(), drops: Arc::default() });
+        let view = owner.view::<dyn Probe>();
+        let reader = {
+            let view = view.clone();
+            thread::spawn(move || while view.with(|probe| assert!(probe.alive())).is_some() {})
+        };
+        match drop_async {
+            true => block_on(owner.drop_async()),
+            false => assert!(owner.drop_and_wait(Duration::from_secs(60)).is_ok())
+        }
+        assert_eq!(view.try_upgrade().err(), Some(ViewError::Dropped));
+        reader.join().unwrap();
+    }
+}
+
+#[test]
+fn suspend_waits_for_upgrades_held_on_other_threads() {
+    for release_with in [false, true] {
+        let owner = DependentArc::new(Dancer { dropped: Arc::default(), drops: Arc::default() });
+        let view = owner.view::<dyn Probe>();
+        let released = Arc::new(AtomicBool::new(false));
+        let barrier = Arc::new(Barrier::new(2));
+
+        let holder = {
+            let (view, released, barrier) = (view.clone(), released.clone(), barrier.clone());
+            thread::spawn(move || {
+                let hold = || {
+                    barrier.wait();
+                    thread::sleep(Duration::from_millis(20));
+                    released.store(true, Ordering::SeqCst);
+                };
+                // references borrowed through `with` wake the owner, those returned by `upgrade` don't
+                match release_with {
+                    true => view.with(|_| hold()).unwrap(),
+                    false => view.upgrade().map(|_probe| hold()).unwrap()
+                }
+            })
+        };
+
+        barrier.wait();
+        let guard = owner.suspend();
+        assert!(released.load(Ordering::SeqCst));
+        assert!(view.upgrade().is_none());
+        drop(guard);
+        assert!(view.upgrade().is_some());
+        holder.join().unwrap();
+    }
+}
@@ -0,0 +1,181 @@
+#[macro_use]
+extern crate dependent_view;
+
+use dependent_view::arc::{DependentArc, SyncView};
+use dependent_view::rc::{DependentRc, View};
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32
+}
+
+#[test]
+fn concrete_views() {
+    let point = DependentRc::new(Point { x: 1, y: 2 });
+    let view : View<Point> = to_view!(point);
+    let generic = point.view::<Point>();
+    assert_eq!(*view.upgrade().unwrap(), Point { x: 1, y: 2 });
+    assert_eq!(generic.upgrade().unwrap().y, 2);
+    drop(point);
+    assert!(view.upgrade().is_none());
+    assert!(generic.upgrade().is_none());
+
+    let point = DependentArc::new(Point { x: 3, y: 4 });
+    let view : SyncView<Point> = to_view_sync!(point => Point);
+    assert_eq!(view.upgrade().unwrap().x, 3);
+    drop(point);
+    assert!(view.upgrade().is_none());
+}
+
+#[test]
+fn slice_views() {
+    let bytes = DependentRc::new([7u8; 16]);
+    let view = to_view!(bytes => [u8]);
+    let generic = bytes.view::<[u8]>();
+    assert_eq!(view.upgrade().unwrap().len(), 16);
+    assert_eq!(generic.upgrade().unwrap().iter().map(|&b| b as usize).sum::<usize>(), 7 * 16);
+    drop(bytes);
+    assert!(view.upgrade().is_none());
+
+    let names = DependentArc::new([String::from("a"), String::from("b")]);
+    let view : SyncView<[String]> = to_view_sync!(names);
+    assert_eq!(view.upgrade().unwrap()[1], "b");
+    // dropping the owner with the view alive frees the strings exactly once
+    drop(names);
+    assert!(view.upgrade().is_none());
+    drop(view);
+}
+
+#[test]
+fn str_views() {
+    let name = DependentRc::new(String::from("dancer"));
+    let view = name.project(String::as_str);
+    assert_eq!(&*view.upgrade().unwrap(), "dancer");
+    drop(name);
+    assert!(view.upgrade().is_none());
+
+    let name : DependentRc<str> = DependentRc::from(Box::<str>::from("dancer"));
+    let view = to_view!(name => str);
+    assert_eq!(&*view.upgrade().unwrap(), "dancer");
+    drop(name);
+    assert!(view.upgrade().is_none());
+
+    let name = DependentArc::new(Box::<str>::from("prancer"));
+    let view = name.project(|name| &**name);
+    assert_eq!(view.upgrade().unwrap().len(), 7);
+    drop(name);
+    assert!(view.upgrade().is_none());
+}
+
+trait Entity {
+    fn id(&self) -> usize;
+}
+
+trait Player: Entity {
+    fn name(&self) -> &str;
+}
+
+impl Entity for Point {
+    fn id(&self) -> usize { self.x as usize }
+}
+
+impl Player for Point {
+    fn name(&self) -> &str { "point" }
+}
+
+impl_views!(dyn Player: dyn Entity);
+
+fn entity_view<T: ?Sized + dependent_view::ViewAs<dyn Entity> + 'static>(owner: &DependentRc<T>) -> View<dyn Entity> {
+    owner.view::<dyn Entity>()
+}
+
+#[test]
+fn supertrait_views_of_unsized_owners() {
+    let player : DependentRc<dyn Player> = DependentRc::from(Box::new(Point { x: 5, y: 0 }) as Box<dyn Player>);
+    let entity = entity_view(&player);
+    assert_eq!(entity.upgrade().unwrap().id(), 5);
+    assert_eq!(player.view::<dyn Player>().upgrade().unwrap().name(), "point");
+    drop(player);
+    assert!(entity.upgrade().is_none());
+
+    let slice : DependentArc<[u8]> = DependentArc::from(vec![1u8, 2, 3].into_boxed_slice());
+    let view = to_view_sync!(slice => [u8]);
+    assert_eq!(view.upgrade().unwrap().len(), 3);
+}
+
+struct Team {
+    lead: Point,
+    reserve: Point
+}
+
+impl Entity for Team {
+    fn id(&self) -> usize { 0 }
+}
+
+impl_views!(Team: dyn Entity, dyn Entity + Send + Sync);
+
+fn lead(team: &Team) -> &Point {
+    &team.lead
+}
+
+#[test]
+fn projections_are_revoked_apart_from_views() {
+    let team = DependentRc::new(Team { lead: Point { x: 1, y: 0 }, reserve: Point { x: 2, y: 0 } });
+    let view = team.view::<dyn Entity>();
+    let (lead, handle) = team.revocable_project::<dyn Entity, _>(|team| &team.lead);
+    team.revoke::<dyn Entity>();
+    assert!(view.upgrade().is_none());
+    assert_eq!(lead.upgrade().unwrap().id(), 1);
+
+    let view = team.view::<dyn Entity>();
+    handle.revoke();
+    assert!(lead.upgrade().is_none());
+    assert_eq!(view.upgrade().unwrap().id(), 0);
+
+    let team = DependentArc::new(Team { lead: Point { x: 3, y: 0 }, reserve: Point { x: 4, y: 0 } });
+    let view = team.view::<dyn Entity + Send + Sync>();
+    let (lead, handle) = team.revocable_project::<dyn Entity + Send + Sync, _>(|team| &team.lead);
+    team.revoke::<dyn Entity + Send + Sync>();
+    assert!(view.upgrade().is_none());
+    assert_eq!(lead.upgrade().unwrap().id(), 3);
+
+    let view = team.view::<dyn Entity + Send + Sync>();
+    handle.revoke();
+    assert!(lead.upgrade().is_none());
+    assert_eq!(view.upgrade().unwrap().id(), 0);
+}
+
+#[test]
+fn projections_of_the_same_field_are_revoked_separately() {
+    let team = DependentRc::new(Team { lead: Point { x: 1, y: 0 }, reserve: Point { x: 2, y: 0 } });
+    // the same field projected from different call sites
+    let (first, first_handle) = team.revocable_project(|team| &team.lead);
+    let (second, _) = team.revocable_project(|team| &team.lead);
+    let copy = first.clone();
+    first_handle.revoke();
+    assert!(first.upgrade().is_none());
+    assert!(copy.upgrade().is_none());
+    assert_eq!(second.upgrade().unwrap().x, 1);
+
+    // independent projections through the same function
+    let (third, third_handle) = team.revocable_project(lead);
+    let fourth = team.project(lead);
+    third_handle.revoke();
+    assert!(third.upgrade().is_none());
+    assert_eq!(fourth.upgrade().unwrap().x, 1);
+    assert_eq!(team.project(|team| &team.reserve).upgrade().unwrap().x, 2);
+
+    let team = DependentArc::new(Team { lead: Point { x: 3, y: 0 }, reserve: Point { x: 4, y: 0 } });
+    let (first, first_handle) = team.revocable_project(|team| &team.lead);
+    let (second, _) = team.revocable_project(|team| &team.lead);
+    first_handle.revoke();
+    assert!(first.upgrade().is_none());
+    assert_eq!(second.upgrade().unwrap().x, 3);
+
+    let (third, third_handle) = team.revocable_project(lead);
+    let fourth = team.project(lead);
+    third_handle.revoke();
+    assert!(third.upgrade().is_none());
+    assert_eq!(fourth.upgrade().unwrap().x, 3);
+}